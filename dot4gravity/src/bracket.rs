@@ -0,0 +1,61 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers to run single elimination tournaments over finished games.
+//!
+//! A bye is represented as a match without an opponent, and always advances its player.
+
+/// Why a round can't be advanced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BracketError {
+    /// The match at this index has two players but no winner yet.
+    UndecidedMatch(usize),
+}
+
+/// Produce the pairings of the next round from the results of the current one.
+///
+/// Each result holds the players of a match and its winner. Every match between two players must
+/// have a winner, draws have to be settled (e.g. with a rematch) before advancing. Winners are
+/// paired in order, and the last one gets a bye when their number is odd. Once a single winner
+/// remains the tournament is over and no pairings are returned.
+pub fn advance_round<Player: Clone>(
+    results: &[(Player, Option<Player>, Option<Player>)],
+) -> Result<Vec<(Player, Option<Player>)>, BracketError> {
+    let winners = results
+        .iter()
+        .enumerate()
+        .map(
+            |(index, (player, opponent, winner))| match (opponent, winner) {
+                (None, _) => Ok(player.clone()),
+                (Some(_), Some(winner)) => Ok(winner.clone()),
+                (Some(_), None) => Err(BracketError::UndecidedMatch(index)),
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if winners.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    Ok(winners
+        .chunks(2)
+        .map(|pair| match pair {
+            [player1, player2] => (player1.clone(), Some(player2.clone())),
+            [bye] => (bye.clone(), None),
+            _ => unreachable!("chunks are never empty nor bigger than 2"),
+        })
+        .collect())
+}
@@ -17,43 +17,78 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use crate::traits::Bound;
+use bounded_collections::{BoundedVec, ConstU32};
 use core::marker::PhantomData;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{prelude::vec::Vec, TypeInfo};
 
+#[cfg(feature = "std")]
+pub mod bracket;
 #[cfg(test)]
 mod tests;
 mod traits;
 
 const INITIAL_SEED: Seed = 123_456;
-const INCREMENT: Seed = 74;
-const MULTIPLIER: Seed = 75;
-const MODULUS: Seed = Seed::pow(2, 16);
+const INCREMENT: Seed = 1_442_695_040_888_963_407;
+const MULTIPLIER: Seed = 6_364_136_223_846_793_005;
+const MAX_BLOCK_PLACEMENT_ATTEMPTS: u32 = 1 << 16;
 
 const BOARD_WIDTH: u8 = 10;
 const BOARD_HEIGHT: u8 = 10;
-const NUM_OF_PLAYERS: usize = 2;
+const MIN_BOARD_SIZE: u8 = 2;
+const MAX_BOARD_WIDTH: u8 = 15;
+const MAX_BOARD_HEIGHT: u8 = 15;
+const MIN_NUM_OF_PLAYERS: usize = 2;
+const MAX_NUM_OF_PLAYERS: usize = 4;
 const NUM_OF_BOMBS_PER_PLAYER: usize = 3;
 const NUM_OF_BLOCKS: u8 = 10;
+const NUM_OF_SQUARES_TO_WIN: u8 = 3;
+const SCORCHED_FLAT_CELL: u8 = 2 + MAX_NUM_OF_PLAYERS as u8;
 
+type BlockNumber = u32;
 type PlayerIndex = u8;
 type Position = u8;
-type Seed = u32;
+type Seed = u64;
+
+pub type MaxPlayers = ConstU32<{ MAX_NUM_OF_PLAYERS as u32 }>;
+pub type Players<Player> = BoundedVec<Player, MaxPlayers>;
+pub type PlayerBombs<Player> =
+    BoundedVec<(Player, [BombState; NUM_OF_BOMBS_PER_PLAYER]), MaxPlayers>;
+
+/// PCG-XSH-RR: a 64 bit linear congruential generator whose state is permuted into the 32 bit
+/// output. The state is advanced before being permuted, so that small seeds don't all start with
+/// the same output.
+fn permuted_congruential_generator(seed: Seed) -> (u32, Seed) {
+    let seed = seed.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT);
+    let xorshifted = (((seed >> 18) ^ seed) >> 27) as u32;
+    let rotation = (seed >> 59) as u32;
+    (xorshifted.rotate_right(rotation), seed)
+}
 
 /// Represents the sate of a placed bomb.
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, Debug, Eq, PartialEq)]
 pub enum BombState {
     NotPlaced,
+    /// Hash of the bomb coordinates along with the secret used to compute it.
+    ///
+    /// This doesn't hide the bomb: the secret is part of the public game state and
+    /// [`Game::drop_bomb`] receives the coordinates in clear, so anyone reading the state or the
+    /// calls can find where bombs are.
     Placed(CoordinatesHash, u64),
     Detonated,
 }
 
 /// Represents a cell of the board.
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, Debug, Eq, PartialEq)]
-enum Cell {
+pub enum Cell {
     Empty,
     Block,
     Stone(PlayerIndex),
+    /// Left behind by an explosion when [`GameConfig::scorch_duration`] is set. Behaves like a
+    /// block until the game reaches the given move index, then turns back into an empty cell.
+    Scorched {
+        until_move: u32,
+    },
 }
 
 impl Default for Cell {
@@ -75,7 +110,29 @@ impl Cell {
 
     /// Tells if a cell is suitable for dropping a stone.
     fn is_stone_droppable(&self) -> bool {
-        !matches!(self, Cell::Block | Cell::Stone(_))
+        !matches!(self, Cell::Block | Cell::Stone(_) | Cell::Scorched { .. })
+    }
+
+    /// Byte representing the cell: 0 for empty, 1 for block, 2 + player index for stones and
+    /// 2 + [`MAX_NUM_OF_PLAYERS`] for scorched cells, whose expiry is left out.
+    fn to_flat(self) -> u8 {
+        match self {
+            Cell::Empty => 0,
+            Cell::Block => 1,
+            Cell::Stone(player_index) => player_index.saturating_add(2),
+            Cell::Scorched { .. } => SCORCHED_FLAT_CELL,
+        }
+    }
+
+    /// Scorched cells come back already expired, so they cool down on the next action.
+    fn from_flat(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Cell::Empty),
+            1 => Some(Cell::Block),
+            SCORCHED_FLAT_CELL => Some(Cell::Scorched { until_move: 0 }),
+            byte if ((byte - 2) as usize) < MAX_NUM_OF_PLAYERS => Some(Cell::Stone(byte - 2)),
+            _ => None,
+        }
     }
 }
 
@@ -104,34 +161,95 @@ impl Coordinates {
         self.generate_hash(secret) == other_hash
     }
 
-    fn random(seed: Seed) -> (Self, Seed) {
-        let linear_congruential_generator = |seed: Seed| -> Seed {
-            MULTIPLIER.saturating_mul(seed).saturating_add(INCREMENT) % MODULUS
-        };
-
-        let random_seed_1 = linear_congruential_generator(seed);
-        let random_seed_2 = linear_congruential_generator(random_seed_1);
+    fn random(seed: Seed, board: &Board) -> (Self, Seed) {
+        let (random_1, seed) = permuted_congruential_generator(seed);
+        let (random_2, seed) = permuted_congruential_generator(seed);
 
         (
             Coordinates::new(
-                (random_seed_1 % (BOARD_WIDTH as Seed - 1)) as u8,
-                (random_seed_2 % (BOARD_HEIGHT as Seed - 1)) as u8,
+                (random_1 % board.height as u32) as u8,
+                (random_2 % board.width as u32) as u8,
             ),
-            random_seed_2,
+            seed,
         )
     }
 
+    /// Number of cells between the coordinates and the closest edge of the board.
+    fn distance_to_edge(&self, board: &Board) -> u8 {
+        self.row
+            .min(self.col)
+            .min(board.height - 1 - self.row)
+            .min(board.width - 1 - self.col)
+    }
+
+    /// Weight of the coordinates based on their closeness to the center of the board. Corners
+    /// weigh 0 and the weight increases by 1 for every half step towards the center.
+    fn centrality(&self, board: &Board) -> u8 {
+        let max_distance = (board.height - 1) + (board.width - 1);
+        let distance =
+            (2 * self.row).abs_diff(board.height - 1) + (2 * self.col).abs_diff(board.width - 1);
+        max_distance - distance
+    }
+
+    /// Half of the board the coordinates are in, along the given axis.
+    pub fn half(&self, axis: Axis, board: &Board) -> Half {
+        let (index, size) = match axis {
+            Axis::Row => (self.row, board.height),
+            Axis::Col => (self.col, board.width),
+        };
+        if index < size / 2 {
+            Half::Lower
+        } else {
+            Half::Upper
+        }
+    }
+
+    /// Quadrant of the board the coordinates are in.
+    pub fn board_quadrant(&self, board: &Board) -> Quadrant {
+        match (self.half(Axis::Row, board), self.half(Axis::Col, board)) {
+            (Half::Lower, Half::Lower) => Quadrant::NorthWest,
+            (Half::Lower, Half::Upper) => Quadrant::NorthEast,
+            (Half::Upper, Half::Lower) => Quadrant::SouthWest,
+            (Half::Upper, Half::Upper) => Quadrant::SouthEast,
+        }
+    }
+
     /// Tells if a cell is in the opposite of a side.
-    fn is_opposite_cell(&self, side: Side) -> bool {
+    fn is_opposite_cell(&self, side: Side, board: &Board) -> bool {
         match side {
-            Side::North => self.row == BOARD_HEIGHT - 1,
+            Side::North => self.row == board.height - 1,
             Side::East => self.col == 0,
             Side::South => self.row == 0,
-            Side::West => self.col == BOARD_WIDTH - 1,
+            Side::West => self.col == board.width - 1,
         }
     }
 }
 
+/// Axis along which the board can be split in halves.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
+/// Halves of the board along an axis.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Half {
+    /// Lower indices: the north half for rows, the west half for columns.
+    Lower,
+    /// Higher indices: the south half for rows, the east half for columns.
+    Upper,
+}
+
+/// Quadrants of the board.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Quadrant {
+    NorthWest,
+    NorthEast,
+    SouthWest,
+    SouthEast,
+}
+
 /// Sides of the board from which a player can drop a stone.
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Side {
@@ -142,19 +260,102 @@ pub enum Side {
 }
 
 impl Side {
-    fn bound_coordinates(&self, position: Position) -> Coordinates {
+    fn bound_coordinates(&self, position: Position, board: &Board) -> Coordinates {
         match self {
             Side::North => Coordinates::new(0, position),
-            Side::South => Coordinates::new(BOARD_HEIGHT - 1, position),
+            Side::South => Coordinates::new(board.height - 1, position),
             Side::West => Coordinates::new(position, 0),
-            Side::East => Coordinates::new(position, BOARD_WIDTH - 1),
+            Side::East => Coordinates::new(position, board.width - 1),
         }
     }
 }
 
-#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Eq, Debug, Default, PartialEq)]
+/// Characters used to render each kind of cell.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CellGlyphs {
+    pub empty: char,
+    pub block: char,
+    /// Stone glyph of each player, by player index.
+    pub stones: [char; MAX_NUM_OF_PLAYERS],
+    pub scorched: char,
+}
+
+impl Default for CellGlyphs {
+    fn default() -> Self {
+        Self {
+            empty: '.',
+            block: '#',
+            stones: ['o', 'x', '+', '@'],
+            scorched: '~',
+        }
+    }
+}
+
+impl CellGlyphs {
+    /// Character for the given cell.
+    pub fn glyph(&self, cell: Cell) -> char {
+        match cell {
+            Cell::Empty => self.empty,
+            Cell::Block => self.block,
+            Cell::Stone(player_index) => self
+                .stones
+                .get(player_index as usize)
+                .copied()
+                .unwrap_or('?'),
+            Cell::Scorched { .. } => self.scorched,
+        }
+    }
+}
+
+/// Dimensions of the board, and number of blocks placed on it when a game is created.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoardConfig {
+    pub width: u8,
+    pub height: u8,
+    pub num_blocks: u8,
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        Self {
+            width: BOARD_WIDTH,
+            height: BOARD_HEIGHT,
+            num_blocks: NUM_OF_BLOCKS,
+        }
+    }
+}
+
+#[derive(Encode, TypeInfo, MaxEncodedLen, Copy, Clone, Eq, Debug, PartialEq)]
 pub struct Board {
-    cells: [[Cell; BOARD_WIDTH as usize]; BOARD_HEIGHT as usize],
+    /// Only the first `height` rows and `width` columns are part of the board.
+    cells: [[Cell; MAX_BOARD_WIDTH as usize]; MAX_BOARD_HEIGHT as usize],
+    width: u8,
+    height: u8,
+}
+
+impl Decode for Board {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let cells = Decode::decode(input)?;
+        let width = u8::decode(input)?;
+        let height = u8::decode(input)?;
+        // Board methods index cells with the size, so it must never exceed the array.
+        if !Board::is_valid_size(width, height) {
+            return Err("Invalid board size".into());
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::with_size(BOARD_WIDTH, BOARD_HEIGHT)
+    }
 }
 
 impl Board {
@@ -162,16 +363,38 @@ impl Board {
         Board::default()
     }
 
+    /// Empty board of the given size. The size must be valid, see [`Board::is_valid_size`].
+    fn with_size(width: u8, height: u8) -> Board {
+        Board {
+            cells: Default::default(),
+            width,
+            height,
+        }
+    }
+
+    fn is_valid_size(width: u8, height: u8) -> bool {
+        (MIN_BOARD_SIZE..=MAX_BOARD_WIDTH).contains(&width)
+            && (MIN_BOARD_SIZE..=MAX_BOARD_HEIGHT).contains(&height)
+    }
+
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    pub fn height(&self) -> u8 {
+        self.height
+    }
+
     fn is_bomb_droppable(&self, position: &Coordinates) -> bool {
-        position.is_inside_board() && self.get_cell(position).is_bomb_droppable()
+        position.is_inside_board(self) && self.get_cell(position).is_bomb_droppable()
     }
 
     fn is_explodable(&self, position: &Coordinates) -> bool {
-        position.is_inside_board() && self.get_cell(position).is_explodable()
+        position.is_inside_board(self) && self.get_cell(position).is_explodable()
     }
 
     fn is_stone_droppable(&self, position: &Coordinates) -> bool {
-        position.is_inside_board() && self.get_cell(position).is_stone_droppable()
+        position.is_inside_board(self) && self.get_cell(position).is_stone_droppable()
     }
 
     fn get_cell(&self, position: &Coordinates) -> Cell {
@@ -187,7 +410,157 @@ impl Board {
         );
     }
 
-    fn explode_bomb(&mut self, bomb_position: Coordinates) {
+    /// Render the board with one character per cell and one line per row.
+    #[cfg(feature = "std")]
+    pub fn render_with(&self, glyphs: &CellGlyphs) -> String {
+        self.cells
+            .iter()
+            .take(self.height as usize)
+            .map(|row| {
+                row.iter()
+                    .take(self.width as usize)
+                    .map(|cell| glyphs.glyph(*cell))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Cells in row-major order, with each cell encoded as a single byte.
+    pub fn to_flat(&self) -> Vec<u8> {
+        self.cells
+            .iter()
+            .take(self.height as usize)
+            .flat_map(|row| {
+                row.iter()
+                    .take(self.width as usize)
+                    .map(|cell| cell.to_flat())
+            })
+            .collect()
+    }
+
+    /// Build a board from its flat representation. Returns `None` when the size is not valid, the
+    /// number of cells doesn't match it or a cell byte is unknown.
+    pub fn from_flat(cells: &[u8], width: u8, height: u8) -> Option<Board> {
+        if !Board::is_valid_size(width, height) || cells.len() != width as usize * height as usize {
+            return None;
+        }
+
+        let mut board = Board::with_size(width, height);
+        for (index, byte) in cells.iter().enumerate() {
+            let position = Coordinates::new(
+                (index / width as usize) as u8,
+                (index % width as usize) as u8,
+            );
+            board.update_cell(position, Cell::from_flat(*byte)?);
+        }
+        Some(board)
+    }
+
+    /// Flat cells packed two per byte, the first one in the high nibble. When the number of cells
+    /// is odd, the low nibble of the last byte is left empty.
+    fn packed_cells(&self) -> Vec<u8> {
+        self.to_flat()
+            .chunks(2)
+            .map(|pair| {
+                let high = pair[0] & 0x0F;
+                let low = pair.get(1).map_or(0, |cell| cell & 0x0F);
+                (high << 4) | low
+            })
+            .collect()
+    }
+
+    fn from_packed(bytes: &[u8], width: u8, height: u8) -> Option<Board> {
+        let num_cells = width as usize * height as usize;
+        if bytes.len() != (num_cells + 1) / 2 {
+            return None;
+        }
+
+        let cells = bytes
+            .iter()
+            .flat_map(|byte| [byte >> 4, byte & 0x0F])
+            .take(num_cells)
+            .collect::<Vec<_>>();
+        Board::from_flat(&cells, width, height)
+    }
+
+    /// Every side and position a stone could be dropped from, whether it is free or not.
+    fn drop_positions(&self) -> impl Iterator<Item = (Side, Position)> {
+        let (width, height) = (self.width, self.height);
+        [Side::North, Side::East, Side::South, Side::West]
+            .into_iter()
+            .flat_map(move |side| {
+                let num_positions = match side {
+                    Side::North | Side::South => width,
+                    Side::East | Side::West => height,
+                };
+                (0..num_positions).map(move |position| (side, position))
+            })
+    }
+
+    /// Cells that differ from another board, along with their old and new contents.
+    pub fn changed_cells(&self, other: &Board) -> Vec<(Coordinates, Cell, Cell)> {
+        let mut changed_cells = Vec::new();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let position = Coordinates::new(row, col);
+                let (old_cell, new_cell) = (self.get_cell(&position), other.get_cell(&position));
+                if old_cell != new_cell {
+                    changed_cells.push((position, old_cell, new_cell));
+                }
+            }
+        }
+        changed_cells
+    }
+
+    /// Find the first player, in turn order, owning the given number of 2x2 squares of stones,
+    /// independently of any game state. Squares are allowed to overlap, see
+    /// [`Board::square_counts`].
+    pub fn compute_winner<Player>(
+        &self,
+        players: &[Player],
+        squares_to_win: u8,
+    ) -> Option<PlayerIndex> {
+        self.square_counts(players.len())
+            .into_iter()
+            .position(|squares| squares >= squares_to_win)
+            .map(|player_index| player_index as PlayerIndex)
+    }
+
+    /// Number of 2x2 squares of stones owned by each player. Squares are allowed to overlap.
+    pub fn square_counts(&self, num_players: usize) -> Vec<u8> {
+        let mut squares = (0..num_players).map(|_| 0_u8).collect::<Vec<_>>();
+        for row in 0..self.height - 1 {
+            for col in 0..self.width - 1 {
+                if let Some(player_index) = self.square_owner(Coordinates::new(row, col)) {
+                    if let Some(player_squares) = squares.get_mut(player_index as usize) {
+                        *player_squares = player_squares.saturating_add(1);
+                    }
+                }
+            }
+        }
+        squares
+    }
+
+    /// Owner of the 2x2 square with the given top left corner, if all its cells are stones of the
+    /// same player.
+    fn square_owner(&self, top_left: Coordinates) -> Option<PlayerIndex> {
+        let Coordinates { row, col } = top_left;
+        let cell = self.get_cell(&top_left);
+        match cell {
+            Cell::Stone(player_index)
+                if cell == self.get_cell(&Coordinates::new(row, col + 1))
+                    && cell == self.get_cell(&Coordinates::new(row + 1, col))
+                    && cell == self.get_cell(&Coordinates::new(row + 1, col + 1)) =>
+            {
+                Some(player_index)
+            }
+            _ => None,
+        }
+    }
+
+    /// Cells that would be cleared by a bomb exploding at the given position.
+    pub fn blast_cells(&self, center: Coordinates) -> Vec<Coordinates> {
         let offsets: [(i8, i8); 9] = [
             (0, 0),
             (-1, -1),
@@ -199,20 +572,69 @@ impl Board {
             (-1, 1),
             (-1, 0),
         ];
-        // Collect the explodable cells around.
+        // Collect the explodable cells around, skipping the ones beyond the edges.
         offsets
             .iter()
-            .map(|(row_offset, col_offset)| {
-                Coordinates::new(
-                    (row_offset + bomb_position.row as i8) as u8,
-                    (col_offset + bomb_position.col as i8) as u8,
-                )
+            .filter_map(|(row_offset, col_offset)| {
+                Some(Coordinates::new(
+                    center.row.checked_add_signed(*row_offset)?,
+                    center.col.checked_add_signed(*col_offset)?,
+                ))
             })
-            .for_each(|position| {
-                if self.is_explodable(&position) {
-                    self.update_cell(position, Cell::Empty)
+            .filter(|position| self.is_explodable(position))
+            .collect()
+    }
+
+    /// Apply an explosion. In [`ExplosionMode::Clear`], cleared cells are scorched until the given
+    /// move index, if any, instead of being emptied.
+    fn explode_bomb(
+        &mut self,
+        bomb_position: Coordinates,
+        mode: ExplosionMode,
+        player_index: PlayerIndex,
+        scorched_until: Option<u32>,
+    ) {
+        let cleared_cell = scorched_until
+            .map(|until_move| Cell::Scorched { until_move })
+            .unwrap_or(Cell::Empty);
+        for position in self.blast_cells(bomb_position) {
+            match mode {
+                ExplosionMode::Clear => self.update_cell(position, cleared_cell),
+                ExplosionMode::Convert => {
+                    if let Cell::Stone(owner) = self.get_cell(&position) {
+                        if owner != player_index {
+                            self.update_cell(position, Cell::Stone(player_index));
+                        }
+                    }
                 }
-            });
+            }
+        }
+    }
+
+    /// Turn the scorched cells whose time is up at the given move index back into empty cells.
+    /// Tells if any cell cooled down.
+    fn cool_down(&mut self, move_index: u32) -> bool {
+        let mut cooled_down = false;
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let position = Coordinates::new(row, col);
+                if let Cell::Scorched { until_move } = self.get_cell(&position) {
+                    if until_move <= move_index {
+                        self.update_cell(position, Cell::Empty);
+                        cooled_down = true;
+                    }
+                }
+            }
+        }
+        cooled_down
+    }
+}
+
+/// Renders the board with the default [`CellGlyphs`].
+#[cfg(feature = "std")]
+impl core::fmt::Display for Board {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(&self.render_with(&CellGlyphs::default()))
     }
 }
 
@@ -230,6 +652,16 @@ impl Default for GamePhase {
     }
 }
 
+impl GamePhase {
+    fn from_flat(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Bomb),
+            1 => Some(Self::Play),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Encode, Decode, TypeInfo, Debug, Eq, PartialEq)]
 pub enum GameError {
     /// Tried to drop a bomb outside bomb phase.
@@ -250,6 +682,28 @@ pub enum GameError {
     NoPreviousPosition,
     /// Tried playing when game has finished.
     GameAlreadyFinished,
+    /// Tried to create a game where the same player appears more than once.
+    DuplicatedPlayer,
+    /// Tried to create a game with too few or too many players.
+    InvalidNumberOfPlayers,
+    /// Tried to rewind to a move beyond the recorded history.
+    InvalidMoveIndex,
+    /// Tried to archive a game that hasn't finished yet.
+    GameNotFinished,
+    /// Tried to drop a bomb too close to the edge of the board.
+    BombInSafeZone,
+    /// Tried to change the game setup after a move was made.
+    GameAlreadyStarted,
+    /// Tried to undo something other than the last stone drop.
+    UndoNotSupported,
+    /// Tried to create a board smaller or bigger than allowed.
+    InvalidBoardSize,
+    /// Tried to create a board with so many blocks that players can't drop all their bombs.
+    TooManyBlocks,
+    /// The player is not part of the game.
+    NotInGame,
+    /// The action was built for another move index, e.g. a resubmitted move.
+    StaleMove,
 }
 
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
@@ -257,45 +711,408 @@ pub struct LastMove<Player> {
     pub player: Player,
     pub side: Side,
     pub position: Position,
+    /// Cell where the stone landed.
+    pub landing: Coordinates,
 }
 
 impl<Player> LastMove<Player> {
-    fn new(player: Player, side: Side, position: Position) -> Self {
+    fn new(player: Player, side: Side, position: Position, landing: Coordinates) -> Self {
         Self {
             player,
             side,
             position,
+            landing,
+        }
+    }
+}
+
+/// What happens to the cells affected by an explosion.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ExplosionMode {
+    /// Every cell but blocks is cleared.
+    Clear,
+    /// Opponent stones are converted to stones of the player detonating the bomb.
+    Convert,
+}
+
+impl Default for ExplosionMode {
+    fn default() -> Self {
+        Self::Clear
+    }
+}
+
+/// What happens during play phase to a player who can neither drop a stone nor detonate a bomb
+/// when their turn comes.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StuckPlayerRule {
+    /// The turn passes to the next player.
+    SkipTurn,
+    /// The player forfeits, see [`Game::forfeit`].
+    Forfeit,
+}
+
+impl Default for StuckPlayerRule {
+    fn default() -> Self {
+        Self::SkipTurn
+    }
+}
+
+/// What's needed to take back the last stone drop, on top of the move itself.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UndoRecord<Player> {
+    /// Last move before the one to take back.
+    pub previous_move: Option<LastMove<Player>>,
+}
+
+/// Rules that can be tuned per game. The default matches the standard rules.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    /// Bombs can't be dropped within this number of cells from the edge of the board.
+    pub bomb_edge_margin: u8,
+    /// Total number of bombs that must be dropped before the game moves on to play phase.
+    /// Bombs not dropped by then are forfeited.
+    pub min_bombs_to_start: u8,
+    /// Effect of bombs on the cells around them.
+    pub explosion_mode: ExplosionMode,
+    /// When set, the game ends in a draw once this number of actions, bomb drops included, has
+    /// been played without a winner.
+    pub max_moves: Option<u32>,
+    /// Handling of players left without any move.
+    pub stuck_player_rule: StuckPlayerRule,
+    /// When set, cells cleared by an explosion are scorched for this number of actions instead of
+    /// being emptied: neither stones nor bombs can be dropped on them. Only applies to
+    /// [`ExplosionMode::Clear`].
+    pub scorch_duration: Option<u32>,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            bomb_edge_margin: 0,
+            min_bombs_to_start: (MAX_NUM_OF_PLAYERS * NUM_OF_BOMBS_PER_PLAYER) as u8,
+            explosion_mode: Default::default(),
+            max_moves: None,
+            stuck_player_rule: Default::default(),
+            scorch_duration: None,
         }
     }
 }
 
+/// An action performed by a player on a game.
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GameAction<Player> {
+    /// Drop a bomb. Only valid during bomb phase.
+    DropBomb {
+        player: Player,
+        coords: Coordinates,
+        secret: u64,
+    },
+    /// Drop a stone. Only valid during play phase.
+    DropStone {
+        player: Player,
+        side: Side,
+        position: Position,
+    },
+    /// Detonate a previously dropped bomb. Only valid during play phase.
+    Detonate {
+        player: Player,
+        coords: Coordinates,
+        secret: u64,
+    },
+}
+
+/// A move in a log of played actions, see [`Game::replay`].
+pub type Move<Player> = GameAction<Player>;
+
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Debug, Eq, PartialEq)]
 pub struct GameState<Player> {
     /// Represents random seed.
     pub seed: Seed,
+    /// Seed the game was created with.
+    pub initial_seed: Seed,
+    /// Block at which the game was created.
+    pub genesis_block: BlockNumber,
     /// Represents the game board.
     pub board: Board,
     /// Game mode.
     pub phase: GamePhase,
     /// When present,it contains the player that won.
     pub winner: Option<Player>,
+    /// Tells if the game ended without a winner.
+    pub is_draw: bool,
     /// Next player turn.
     pub next_player: Player,
     /// Players:
-    pub players: [Player; NUM_OF_PLAYERS],
+    pub players: Players<Player>,
+    /// Players who conceded. They are skipped in turn order.
+    pub forfeited: Players<Player>,
     /// Bomb states for each player
-    pub bombs: [(Player, [BombState; NUM_OF_BOMBS_PER_PLAYER]); NUM_OF_PLAYERS],
+    pub bombs: PlayerBombs<Player>,
     /// Represents the last move.
     pub last_move: Option<LastMove<Player>>,
+    /// When present, it contains the move that won the game.
+    pub winning_move: Option<LastMove<Player>>,
+    /// Number of successful actions since the game was created. Only goes back on undo.
+    pub move_index: u32,
+    /// When present, the last action was a stone drop that can be taken back.
+    pub undo_record: Option<UndoRecord<Player>>,
+    /// Game rules.
+    pub config: GameConfig,
+}
+
+pub type ArchiveChecksum = [u8; 8];
+
+/// Immutable record of a finished game, with everything needed to display or verify it.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Debug, Eq, PartialEq)]
+pub struct GameArchive<Player> {
+    /// Seed the game was created with.
+    pub initial_seed: Seed,
+    /// Block at which the game was created.
+    pub genesis_block: BlockNumber,
+    /// Final board.
+    pub board: Board,
+    pub players: Players<Player>,
+    /// Either a win or a draw.
+    pub outcome: GameOutcome<Player>,
+    /// Number of actions played.
+    pub move_index: u32,
+    /// Move that ended the game.
+    pub last_move: Option<LastMove<Player>>,
+    /// Checksum of all the other fields.
+    pub checksum: ArchiveChecksum,
+}
+
+impl<Player: Encode> GameArchive<Player> {
+    fn compute_checksum(&self) -> ArchiveChecksum {
+        let bytes = (
+            self.initial_seed,
+            self.genesis_block,
+            &self.board,
+            &self.players,
+            &self.outcome,
+            self.move_index,
+            &self.last_move,
+        )
+            .encode();
+        sp_crypto_hashing::twox_64(&bytes)
+    }
+
+    /// Tells if the checksum matches the archived data.
+    pub fn verify(&self) -> bool {
+        self.checksum == self.compute_checksum()
+    }
+}
+
+/// Outcome of dropping a stone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveReport<Player> {
+    /// Cell where the stone landed.
+    pub landing: Coordinates,
+    /// Player whose turn it is after the move.
+    pub next_player: Player,
+    /// When present, the move won the game for this player.
+    pub winner: Option<Player>,
+    /// Tells if the move ended the game in a draw.
+    pub is_draw: bool,
+}
+
+/// Something that happened while applying an action, for indexers and clients.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub enum GameEvent<Player> {
+    StonePlaced {
+        player: Player,
+        coords: Coordinates,
+    },
+    /// A bomb exploded, emptying the given cells.
+    BombExploded {
+        at: Coordinates,
+        cleared: Vec<Coordinates>,
+    },
+    StoneDestroyed {
+        owner: Player,
+        coords: Coordinates,
+    },
+    /// A stone changed hands in an explosion, see [`ExplosionMode::Convert`].
+    StoneConverted {
+        coords: Coordinates,
+        from: Player,
+        to: Player,
+    },
+    PhaseChanged(GamePhase),
+    Winner(Player),
+}
+
+/// How a game stands.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Debug, Eq, PartialEq)]
+pub enum GameOutcome<Player> {
+    InProgress,
+    Win(Player),
+    Draw,
+}
+
+/// Tells if an action can be performed, or why it can't.
+#[derive(Debug, Eq, PartialEq)]
+pub enum MoveLegality {
+    Legal,
+    Illegal(GameError),
+}
+
+/// Board changes made by a single action, see [`AuditLog`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry<Player> {
+    /// Move index of the game before the action.
+    pub move_index: u32,
+    pub action: GameAction<Player>,
+    /// Cells changed by the action, see [`Board::changed_cells`].
+    pub changes: Vec<(Coordinates, Cell, Cell)>,
+}
+
+/// Opt-in record of every board change of a game, for servers to check each one came from a
+/// legal action with [`GameState::verify_audit`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditLog<Player> {
+    /// State the recorded actions were applied to.
+    pub initial: GameState<Player>,
+    pub entries: Vec<AuditEntry<Player>>,
+}
+
+impl<Player: PartialEq + Clone> AuditLog<Player> {
+    pub fn new(initial: GameState<Player>) -> Self {
+        Self {
+            initial,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Apply an action to the game and record the board changes it made. Failed actions are not
+    /// recorded.
+    pub fn apply(
+        &mut self,
+        game_state: GameState<Player>,
+        action: GameAction<Player>,
+    ) -> Result<GameState<Player>, GameError> {
+        let move_index = game_state.move_index;
+        let board_before = game_state.board;
+        let game_state = Game::apply(game_state, action.clone())?;
+        self.entries.push(AuditEntry {
+            move_index,
+            action,
+            changes: board_before.changed_cells(&game_state.board),
+        });
+        Ok(game_state)
+    }
+}
+
+/// Flat representation of a game, trivial to map to other languages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlatGameState<Player> {
+    /// Board cells in row-major order, see [`Board::to_flat`].
+    pub cells: Vec<u8>,
+    pub width: u8,
+    pub height: u8,
+    /// 0 for bomb phase, 1 for play phase.
+    pub phase: u8,
+    /// Players in turn order, stones hold indices into it.
+    pub players: Vec<Player>,
+    pub next_player: Player,
+    pub winner: Option<Player>,
+}
+
+/// Read-only view of a game holding only what's needed to display it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisplayState {
+    pub board: Board,
+    pub phase: GamePhase,
+    pub next_player_index: PlayerIndex,
+}
+
+impl DisplayState {
+    /// Decode a state encoded with [`GameState::encode_minimal`].
+    pub fn decode_minimal(bytes: &[u8]) -> Option<Self> {
+        let (next_player_index, bytes) = bytes.split_last()?;
+        let (phase, bytes) = bytes.split_last()?;
+        let (height, bytes) = bytes.split_last()?;
+        let (width, packed_board) = bytes.split_last()?;
+        Some(Self {
+            board: Board::from_packed(packed_board, *width, *height)?,
+            phase: GamePhase::from_flat(*phase)?,
+            next_player_index: *next_player_index,
+        })
+    }
+}
+
+/// How evenly a starting board is laid out, for organizers to accept or reject a seed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FairnessReport {
+    /// Difference between the number of blocks in the most and the least crowded quadrants of
+    /// the board, 0 when blocks are spread evenly.
+    pub block_balance: i32,
+    /// Number of positions a stone can be dropped from on each side, in north, east, south and
+    /// west order.
+    pub lane_openness: [u8; 4],
+}
+
+/// Summary of the bomb phase.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BombPhaseProgress<Player> {
+    /// Bombs each player still needs to drop.
+    pub remaining: Vec<(Player, u8)>,
+    /// Tells if all bombs have been dropped, so the game can move on to play phase.
+    pub ready_to_advance: bool,
 }
 
 impl<Player: PartialEq + Clone> GameState<Player> {
     fn is_all_bomb_dropped(&self) -> bool {
-        self.bombs
-            .iter()
+        self.active_bombs()
             .all(|(_, state)| state.iter().all(|s| *s != BombState::NotPlaced))
     }
 
+    fn is_bomb_phase_over(&self) -> bool {
+        let dropped_bombs = self
+            .bombs
+            .iter()
+            .flat_map(|(_, state)| state.iter())
+            .filter(|state| **state != BombState::NotPlaced)
+            .count();
+        self.is_all_bomb_dropped() || dropped_bombs >= self.config.min_bombs_to_start as usize
+    }
+
+    fn is_finished(&self) -> bool {
+        self.winner.is_some() || self.is_draw
+    }
+
+    pub fn outcome(&self) -> GameOutcome<Player> {
+        match (&self.winner, self.is_draw) {
+            (Some(winner), _) => GameOutcome::Win(winner.clone()),
+            (None, true) => GameOutcome::Draw,
+            (None, false) => GameOutcome::InProgress,
+        }
+    }
+
+    /// Tells if the game is stuck during play phase: no stone can be dropped from any side and
+    /// no bomb is left to detonate.
+    pub fn is_stalemate(&self) -> bool {
+        self.phase == GamePhase::Play
+            && self.board.drop_positions().all(|(side, position)| {
+                !self
+                    .board
+                    .is_stone_droppable(&side.bound_coordinates(position, &self.board))
+            })
+            && self.active_bombs().all(|(_, bomb_states)| {
+                bomb_states
+                    .iter()
+                    .all(|state| !matches!(state, BombState::Placed(..)))
+            })
+    }
+
+    fn has_started(&self) -> bool {
+        self.last_move.is_some()
+            || self
+                .bombs
+                .iter()
+                .any(|(_, state)| state.iter().any(|s| *s != BombState::NotPlaced))
+    }
+
     fn change_game_phase(&mut self, phase: GamePhase) {
         self.phase = phase
     }
@@ -304,6 +1121,169 @@ impl<Player: PartialEq + Clone> GameState<Player> {
         self.bombs.iter().any(|(p, _)| *p == *player)
     }
 
+    /// Tells if the player could do anything on their turn during play phase: drop a stone from
+    /// some side or detonate one of their bombs.
+    pub fn has_any_legal_move_for(&self, player: &Player) -> bool {
+        let can_drop_stone = || {
+            self.board.drop_positions().any(|(side, position)| {
+                self.board
+                    .is_stone_droppable(&side.bound_coordinates(position, &self.board))
+            })
+        };
+        let can_detonate = || {
+            self.bombs.iter().any(|(p, bomb_states)| {
+                p == player
+                    && bomb_states
+                        .iter()
+                        .any(|state| matches!(state, BombState::Placed(..)))
+            })
+        };
+        self.phase == GamePhase::Play
+            && !self.is_finished()
+            && self.is_player_in_game(player)
+            && !self.has_forfeited(player)
+            && (can_drop_stone() || can_detonate())
+    }
+
+    /// Summarize how blocks and open lanes are distributed across the board, see
+    /// [`FairnessReport`]. Lanes are the ones [`Game::valid_stone_moves`] would offer in play
+    /// phase, regardless of the current phase or turn.
+    pub fn start_fairness(&self) -> FairnessReport {
+        let mut blocks_per_quadrant = [0_i32; 4];
+        for row in 0..self.board.height {
+            for col in 0..self.board.width {
+                let position = Coordinates::new(row, col);
+                if self.board.get_cell(&position) == Cell::Block {
+                    blocks_per_quadrant[position.board_quadrant(&self.board) as usize] += 1;
+                }
+            }
+        }
+
+        let mut lane_openness = [0_u8; 4];
+        for (side, position) in self.board.drop_positions() {
+            if self
+                .board
+                .is_stone_droppable(&side.bound_coordinates(position, &self.board))
+            {
+                lane_openness[side as usize] += 1;
+            }
+        }
+
+        FairnessReport {
+            block_balance: blocks_per_quadrant
+                .iter()
+                .max()
+                .copied()
+                .unwrap_or_default()
+                - blocks_per_quadrant
+                    .iter()
+                    .min()
+                    .copied()
+                    .unwrap_or_default(),
+            lane_openness,
+        }
+    }
+
+    /// Every action the player can perform right now during play phase: stone drops and
+    /// detonations of their placed bombs.
+    ///
+    /// Nothing is listed during bomb phase, because a bomb drop needs a secret only the player
+    /// knows. Use [`Game::valid_bomb_positions`] to know where bombs can be dropped.
+    pub fn available_actions(&self, player: &Player) -> Vec<GameAction<Player>> {
+        let mut actions = Game::valid_stone_moves(self, player)
+            .into_iter()
+            .map(|(side, position)| GameAction::DropStone {
+                player: player.clone(),
+                side,
+                position,
+            })
+            .collect::<Vec<_>>();
+        if Game::can_detonate_bomb(self, player).is_err() {
+            return actions;
+        }
+
+        let cells = (0..self.board.height)
+            .flat_map(|row| (0..self.board.width).map(move |col| Coordinates::new(row, col)));
+        for (_, bomb_states) in self.bombs.iter().filter(|(p, _)| p == player) {
+            for state in bomb_states {
+                if let BombState::Placed(hash, secret) = state {
+                    if let Some(coords) = cells
+                        .clone()
+                        .find(|position| position.generate_hash(*secret) == *hash)
+                    {
+                        actions.push(GameAction::Detonate {
+                            player: player.clone(),
+                            coords,
+                            secret: *secret,
+                        });
+                    }
+                }
+            }
+        }
+        actions
+    }
+
+    /// Number of stones and bombs the player can drop right now, counted without allocating.
+    /// Same as the number of [`Game::valid_stone_moves`] and [`Game::valid_bomb_positions`].
+    pub fn legal_move_count(&self, player: &Player) -> usize {
+        let stone_moves = self
+            .board
+            .drop_positions()
+            .filter(|(side, position)| Game::can_drop_stone(self, side, *position, player).is_ok())
+            .count();
+        let bomb_moves = (0..self.board.height)
+            .flat_map(|row| (0..self.board.width).map(move |col| Coordinates::new(row, col)))
+            .filter(|position| Game::can_drop_bomb(self, player, position).is_ok())
+            .count();
+        stone_moves + bomb_moves
+    }
+
+    /// Tells if the audit log leads to this state: replaying its actions from its initial state
+    /// must succeed, make exactly the recorded board changes and end on this board.
+    pub fn verify_audit(&self, audit: &AuditLog<Player>) -> bool {
+        let mut game_state = audit.initial.clone();
+        for entry in &audit.entries {
+            if game_state.move_index != entry.move_index {
+                return false;
+            }
+            let board_before = game_state.board;
+            match Game::apply(game_state, entry.action.clone()) {
+                Ok(new_state) => game_state = new_state,
+                Err(_) => return false,
+            }
+            if board_before.changed_cells(&game_state.board) != entry.changes {
+                return false;
+            }
+        }
+
+        game_state.board == self.board && game_state.move_index == self.move_index
+    }
+
+    /// Move index the next action must be submitted with, see [`Game::apply_expecting`].
+    pub fn expected_next_move_index(&self) -> u32 {
+        self.move_index
+    }
+
+    pub fn has_forfeited(&self, player: &Player) -> bool {
+        self.forfeited.contains(player)
+    }
+
+    /// Players who haven't conceded, in turn order.
+    fn active_players(&self) -> impl Iterator<Item = &Player> {
+        self.players
+            .iter()
+            .filter(|player| !self.has_forfeited(player))
+    }
+
+    /// Bombs of the players who haven't conceded.
+    fn active_bombs(
+        &self,
+    ) -> impl Iterator<Item = &(Player, [BombState; NUM_OF_BOMBS_PER_PLAYER])> {
+        self.bombs
+            .iter()
+            .filter(|(player, _)| !self.has_forfeited(player))
+    }
+
     pub fn is_player_bomb_at(&self, player: &Player, position: &Coordinates) -> bool {
         self.bombs
             .iter()
@@ -333,26 +1313,354 @@ impl<Player: PartialEq + Clone> GameState<Player> {
             })
     }
 
+    /// Check that the player could drop bombs at all the given positions, without dropping them.
+    pub fn validate_bomb_batch(
+        &self,
+        player: &Player,
+        placements: &[Coordinates],
+    ) -> Result<(), GameError> {
+        let available_bombs = self.get_player_bombs(player).ok_or(GameError::NotInGame)?;
+        if placements.len() > available_bombs as usize {
+            return Err(GameError::NoMoreBombsAvailable);
+        }
+        for (i, position) in placements.iter().enumerate() {
+            Game::can_drop_bomb(self, player, position)?;
+            if placements[..i].contains(position) {
+                return Err(GameError::InvalidBombPosition);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Number of 2x2 squares of stones each player owns on the board, counted the same way as
+    /// the win check.
+    pub fn square_counts(&self) -> Vec<(Player, u8)> {
+        self.players
+            .iter()
+            .cloned()
+            .zip(self.board.square_counts(self.players.len()))
+            .collect()
+    }
+
+    pub fn bomb_phase_progress(&self) -> BombPhaseProgress<Player> {
+        BombPhaseProgress {
+            remaining: self
+                .active_players()
+                .map(|player| {
+                    (
+                        player.clone(),
+                        self.get_player_bombs(player).unwrap_or_default(),
+                    )
+                })
+                .collect(),
+            ready_to_advance: self.is_bomb_phase_over(),
+        }
+    }
+
+    /// List, for every cell, the indices of the players owning a bomb in it.
+    pub fn bomb_ownership_grid(&self) -> Vec<Vec<Vec<PlayerIndex>>> {
+        (0..self.board.height)
+            .map(|row| {
+                (0..self.board.width)
+                    .map(|col| {
+                        let position = Coordinates::new(row, col);
+                        self.players
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, player)| self.is_player_bomb_at(player, &position))
+                            .map(|(index, _)| index as PlayerIndex)
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Render the board followed by the phase and whose turn it is, or who won.
+    #[cfg(feature = "std")]
+    pub fn render(&self) -> String
+    where
+        Player: core::fmt::Debug,
+    {
+        let status = match self.outcome() {
+            GameOutcome::InProgress => format!("next player: {:?}", self.next_player),
+            GameOutcome::Win(winner) => format!("winner: {:?}", winner),
+            GameOutcome::Draw => "draw".to_string(),
+        };
+        format!("{}\nphase: {:?}\n{}", self.board, self.phase, status)
+    }
+
+    /// Full human readable report of the game: the rendered state, the squares of each player,
+    /// the number of moves and the last move.
+    #[cfg(feature = "std")]
+    pub fn explain(&self) -> String
+    where
+        Player: core::fmt::Debug,
+    {
+        let squares = self
+            .square_counts()
+            .iter()
+            .map(|(player, squares)| format!("{:?}: {}", player, squares))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut report = format!(
+            "{}\nsquares: {}\nmoves: {}",
+            self.render(),
+            squares,
+            self.move_index
+        );
+        if let Some(last_move) = self.describe_last_move() {
+            report.push_str(&format!("\nlast move: {}", last_move));
+        }
+        report
+    }
+
+    /// Describe the last move in a human readable way.
+    #[cfg(feature = "std")]
+    pub fn describe_last_move(&self) -> Option<String>
+    where
+        Player: core::fmt::Debug,
+    {
+        self.last_move.as_ref().map(|last_move| {
+            format!(
+                "{:?} dropped a stone from {:?} at position {}, landing at ({},{})",
+                last_move.player,
+                last_move.side,
+                last_move.position,
+                last_move.landing.row,
+                last_move.landing.col,
+            )
+        })
+    }
+
+    /// Tell if an action is legal without applying it.
+    pub fn classify_move(&self, action: &GameAction<Player>) -> MoveLegality {
+        let result = match action {
+            GameAction::DropBomb { player, coords, .. } => {
+                Game::can_drop_bomb(self, player, coords)
+            }
+            GameAction::DropStone {
+                player,
+                side,
+                position,
+            } => Game::can_drop_stone(self, side, *position, player),
+            GameAction::Detonate {
+                player,
+                coords,
+                secret,
+            } => Game::can_detonate_bomb(self, player).and_then(|_| {
+                let coordinate_hash = coords.generate_hash(*secret);
+                let player_index = self.player_index(player);
+                let bomb_placed = self.bombs[player_index as usize].1.iter().any(
+                    |state| matches!(state, BombState::Placed(hash, _) if *hash == coordinate_hash),
+                );
+                if bomb_placed {
+                    Ok(())
+                } else {
+                    Err(GameError::InvalidBombPosition)
+                }
+            }),
+        };
+
+        match result {
+            Ok(()) => MoveLegality::Legal,
+            Err(error) => MoveLegality::Illegal(error),
+        }
+    }
+
+    /// Positional heuristic: the centrality of the player's stones minus the one of the
+    /// opponents' stones.
+    pub fn center_control(&self, player: &Player) -> Result<i32, GameError> {
+        let player_index = self.find_player_index(player).ok_or(GameError::NotInGame)?;
+        let mut control = 0;
+        for row in 0..self.board.height {
+            for col in 0..self.board.width {
+                let position = Coordinates::new(row, col);
+                if let Cell::Stone(owner) = self.board.get_cell(&position) {
+                    let centrality = position.centrality(&self.board) as i32;
+                    if owner == player_index {
+                        control += centrality;
+                    } else {
+                        control -= centrality;
+                    }
+                }
+            }
+        }
+        Ok(control)
+    }
+
+    /// Board as seen by the given player: their stones and the ones of player 0 swap owners, so
+    /// the player's own stones always have index 0.
+    pub fn as_perspective(&self, player: &Player) -> Result<Board, GameError> {
+        let own_index = self.find_player_index(player).ok_or(GameError::NotInGame)?;
+        let mut board = self.board;
+        for row in 0..board.height {
+            for col in 0..board.width {
+                let position = Coordinates::new(row, col);
+                if let Cell::Stone(owner) = board.get_cell(&position) {
+                    let owner = match owner {
+                        owner if owner == own_index => 0,
+                        0 => own_index,
+                        owner => owner,
+                    };
+                    board.update_cell(position, Cell::Stone(owner));
+                }
+            }
+        }
+        Ok(board)
+    }
+
+    /// Produce an archival record of a finished game.
+    pub fn archive(&self) -> Result<GameArchive<Player>, GameError>
+    where
+        Player: Encode,
+    {
+        if !self.is_finished() {
+            return Err(GameError::GameNotFinished);
+        }
+        let mut archive = GameArchive {
+            initial_seed: self.initial_seed,
+            genesis_block: self.genesis_block,
+            board: self.board,
+            players: self.players.clone(),
+            outcome: self.outcome(),
+            move_index: self.move_index,
+            last_move: self.last_move.clone(),
+            checksum: Default::default(),
+        };
+        archive.checksum = archive.compute_checksum();
+        Ok(archive)
+    }
+
+    /// Smallest encoding of the game for display purposes: the packed board followed by its
+    /// width and height, the phase and the index of the next player. Decode it with
+    /// [`DisplayState::decode_minimal`].
+    pub fn encode_minimal(&self) -> Vec<u8> {
+        let mut bytes = self.board.packed_cells();
+        bytes.extend([self.board.width, self.board.height]);
+        bytes.push(self.phase as u8);
+        bytes.push(self.next_player_index());
+        bytes
+    }
+
+    pub fn to_flat(&self) -> FlatGameState<Player> {
+        FlatGameState {
+            cells: self.board.to_flat(),
+            width: self.board.width,
+            height: self.board.height,
+            phase: self.phase as u8,
+            players: self.players.to_vec(),
+            next_player: self.next_player.clone(),
+            winner: self.winner.clone(),
+        }
+    }
+
+    /// Rebuild a game from its flat representation. Returns `None` when the flat state is not
+    /// consistent, e.g. a stone belongs to no player or the next player isn't in the game.
+    ///
+    /// The flat representation doesn't hold the seed, bombs nor move history, so the game starts
+    /// over from the default seed, with no bomb on the board and no move to undo.
+    pub fn from_flat(flat: FlatGameState<Player>) -> Option<Self> {
+        let board = Board::from_flat(&flat.cells, flat.width, flat.height)?;
+        let num_players = flat.players.len();
+        let has_unknown_owner = flat
+            .cells
+            .iter()
+            .filter_map(|byte| Cell::from_flat(*byte))
+            .any(|cell| matches!(cell, Cell::Stone(owner) if owner as usize >= num_players));
+        if has_unknown_owner
+            || !flat.players.contains(&flat.next_player)
+            || matches!(&flat.winner, Some(winner) if !flat.players.contains(winner))
+        {
+            return None;
+        }
+
+        let mut game_state = Game::new_game(
+            flat.players,
+            None,
+            Default::default(),
+            Some(BoardConfig {
+                width: flat.width,
+                height: flat.height,
+                num_blocks: 0,
+            }),
+        )
+        .ok()?;
+        game_state.board = board;
+        game_state.phase = GamePhase::from_flat(flat.phase)?;
+        game_state.next_player = flat.next_player;
+        game_state.winner = flat.winner;
+        Some(game_state)
+    }
+
+    /// Deterministically pick a player from the game seed, for formats that need a winner even
+    /// when the game is drawn.
+    pub fn resolve_draw_with_seed(&self) -> Player {
+        // The low bits of the seed follow a short cycle, so pick from a hash of the whole seed.
+        let hash = u64::from_le_bytes(sp_crypto_hashing::twox_64(&self.seed.encode()));
+        let active_players = self.active_players().collect::<Vec<_>>();
+        active_players[(hash % active_players.len() as u64) as usize].clone()
+    }
+
+    /// Compare with another state, ignoring the current random seed.
+    pub fn eq_ignoring_seed(&self, other: &Self) -> bool {
+        let mut other = other.clone();
+        other.seed = self.seed;
+        *self == other
+    }
+
+    pub fn current_seed(&self) -> Seed {
+        self.seed
+    }
+
+    /// Override the random seed. Only allowed before any move is made.
+    pub fn reseed(&mut self, seed: Seed) -> Result<(), GameError> {
+        if self.has_started() {
+            return Err(GameError::GameAlreadyStarted);
+        }
+
+        self.seed = seed;
+        Ok(())
+    }
+
+    /// Blocks elapsed since the game was created.
+    pub fn elapsed_blocks(&self, current_block: BlockNumber) -> BlockNumber {
+        current_block.saturating_sub(self.genesis_block)
+    }
+
     pub fn is_player_turn(&self, player: &Player) -> bool {
         self.next_player == *player
     }
 
+    /// Index of the player whose turn it is.
+    pub fn next_player_index(&self) -> PlayerIndex {
+        self.player_index(&self.next_player)
+    }
+
     fn player_index(&self, player: &Player) -> PlayerIndex {
-        let player_index = self
-            .players
+        self.find_player_index(player)
+            .expect("player to be part of the game")
+    }
+
+    fn find_player_index(&self, player: &Player) -> Option<PlayerIndex> {
+        self.players
             .iter()
             .position(|this_player| this_player == player)
-            .expect("game to always start with 2 players") as u8;
-        player_index
+            .map(|index| index as PlayerIndex)
     }
 
+    /// Player following the current one in turn order, skipping the ones who conceded.
     fn next_player(&self) -> &Player {
         let current_player_index = self
             .players
             .iter()
             .position(|player| *player == self.next_player)
             .expect("next player to be a subset of players");
-        &self.players[(current_player_index + 1) % NUM_OF_PLAYERS]
+        (1..=self.players.len())
+            .map(|offset| &self.players[(current_player_index + offset) % self.players.len()])
+            .find(|player| !self.has_forfeited(player))
+            .unwrap_or(&self.next_player)
     }
 }
 
@@ -368,12 +1676,18 @@ impl<Player: PartialEq + Clone> Game<Player> {
         if game_state.phase != GamePhase::Bomb {
             return Err(GameError::DroppedBombOutsideBombPhase);
         }
-        if game_state.winner.is_some() {
+        if game_state.is_finished() {
             return Err(GameError::GameAlreadyFinished);
         }
+        if !game_state.is_player_in_game(player) || game_state.has_forfeited(player) {
+            return Err(GameError::NotInGame);
+        }
         if !game_state.board.is_bomb_droppable(position) {
             return Err(GameError::InvalidBombPosition);
         }
+        if position.distance_to_edge(&game_state.board) < game_state.config.bomb_edge_margin {
+            return Err(GameError::BombInSafeZone);
+        }
         if game_state.is_all_player_bomb_dropped(player) {
             return Err(GameError::NoMoreBombsAvailable);
         }
@@ -388,7 +1702,7 @@ impl<Player: PartialEq + Clone> Game<Player> {
         if game_state.phase != GamePhase::Play {
             return Err(GameError::DetonatedBombOutsidePlayPhase);
         }
-        if game_state.winner.is_some() {
+        if game_state.is_finished() {
             return Err(GameError::GameAlreadyFinished);
         }
         if !game_state.is_player_turn(player) {
@@ -407,7 +1721,7 @@ impl<Player: PartialEq + Clone> Game<Player> {
         if game_state.phase != GamePhase::Play {
             return Err(GameError::DroppedStoneOutsidePlayPhase);
         }
-        if game_state.winner.is_some() {
+        if game_state.is_finished() {
             return Err(GameError::GameAlreadyFinished);
         }
         if !game_state.is_player_turn(player) {
@@ -415,7 +1729,7 @@ impl<Player: PartialEq + Clone> Game<Player> {
         }
         if !game_state
             .board
-            .is_stone_droppable(&side.bound_coordinates(position))
+            .is_stone_droppable(&side.bound_coordinates(position, &game_state.board))
         {
             return Err(GameError::InvalidStonePosition);
         }
@@ -425,16 +1739,53 @@ impl<Player: PartialEq + Clone> Game<Player> {
 }
 
 impl<Player: PartialEq + Clone> Game<Player> {
-    /// Create a new game.
-    pub fn new_game(player1: Player, player2: Player, seed: Option<Seed>) -> GameState<Player> {
-        let mut board = Board::new();
+    /// Create a new game between 2 to 4 players. The first player starts the game. The board
+    /// defaults to [`BoardConfig::default`] when no configuration is given.
+    pub fn new_game(
+        players: Vec<Player>,
+        seed: Option<Seed>,
+        genesis_block: BlockNumber,
+        board_config: Option<BoardConfig>,
+    ) -> Result<GameState<Player>, GameError> {
+        if !(MIN_NUM_OF_PLAYERS..=MAX_NUM_OF_PLAYERS).contains(&players.len()) {
+            return Err(GameError::InvalidNumberOfPlayers);
+        }
+        if players
+            .iter()
+            .enumerate()
+            .any(|(index, player)| players[..index].contains(player))
+        {
+            return Err(GameError::DuplicatedPlayer);
+        }
+
+        let board_config = board_config.unwrap_or_default();
+        if !Board::is_valid_size(board_config.width, board_config.height) {
+            return Err(GameError::InvalidBoardSize);
+        }
+        // Each player needs a distinct free cell for every bomb.
+        if board_config.num_blocks as usize + NUM_OF_BOMBS_PER_PLAYER
+            > board_config.width as usize * board_config.height as usize
+        {
+            return Err(GameError::TooManyBlocks);
+        }
+
+        let mut board = Board::with_size(board_config.width, board_config.height);
         let mut blocks = Vec::new();
-        let mut remaining_blocks = NUM_OF_BLOCKS;
+        let mut remaining_blocks = board_config.num_blocks;
 
-        let mut seed = seed.unwrap_or(INITIAL_SEED);
+        let initial_seed = seed.unwrap_or(INITIAL_SEED);
+        let mut seed = initial_seed;
 
+        // Random positions may keep hitting existing blocks on a nearly full board, so give up
+        // after a bounded number of draws.
+        let mut attempts = 0;
         while remaining_blocks > 0 {
-            let (block_coordinates, new_seed) = Coordinates::random(seed);
+            if attempts > MAX_BLOCK_PLACEMENT_ATTEMPTS {
+                return Err(GameError::TooManyBlocks);
+            }
+            attempts += 1;
+
+            let (block_coordinates, new_seed) = Coordinates::random(seed, &board);
             seed = new_seed;
             if !blocks.contains(&block_coordinates) {
                 blocks.push(block_coordinates);
@@ -443,19 +1794,135 @@ impl<Player: PartialEq + Clone> Game<Player> {
             }
         }
 
-        GameState {
+        Ok(GameState {
             seed,
+            initial_seed,
+            genesis_block,
             board,
             phase: Default::default(),
             winner: Default::default(),
-            next_player: player1.clone(),
-            players: [player1.clone(), player2.clone()],
-            bombs: [
-                (player1, [BombState::NotPlaced; NUM_OF_BOMBS_PER_PLAYER]),
-                (player2, [BombState::NotPlaced; NUM_OF_BOMBS_PER_PLAYER]),
-            ],
+            is_draw: false,
+            next_player: players[0].clone(),
+            bombs: PlayerBombs::truncate_from(
+                players
+                    .iter()
+                    .map(|player| {
+                        (
+                            player.clone(),
+                            [BombState::NotPlaced; NUM_OF_BOMBS_PER_PLAYER],
+                        )
+                    })
+                    .collect(),
+            ),
+            players: Players::truncate_from(players),
+            forfeited: Default::default(),
             last_move: Default::default(),
+            winning_move: Default::default(),
+            move_index: 0,
+            undo_record: Default::default(),
+            config: Default::default(),
+        })
+    }
+
+    /// Create a new game where player indices don't depend on the order in which the players are
+    /// passed. Players are ordered by their encoded identities, so the same players always get the
+    /// same index. The first player still starts the game.
+    pub fn new_game_with_canonical_order(
+        players: Vec<Player>,
+        seed: Option<Seed>,
+        genesis_block: BlockNumber,
+        board_config: Option<BoardConfig>,
+    ) -> Result<GameState<Player>, GameError>
+    where
+        Player: Encode,
+    {
+        let mut game_state = Self::new_game(players, seed, genesis_block, board_config)?;
+        game_state.players.sort_by_key(|player| player.encode());
+        game_state.bombs.sort_by_key(|(player, _)| player.encode());
+        Ok(game_state)
+    }
+
+    /// Create a new game where the turn order is shuffled with the game seed instead of following
+    /// the order in which the players are passed. The same seed always gives the same order, and
+    /// the first player in it starts the game.
+    pub fn new_game_with_shuffled_turn_order(
+        players: Vec<Player>,
+        seed: Option<Seed>,
+        genesis_block: BlockNumber,
+        board_config: Option<BoardConfig>,
+    ) -> Result<GameState<Player>, GameError> {
+        let mut game_state = Self::new_game(players, seed, genesis_block, board_config)?;
+        // Fisher-Yates shuffle, keeping bombs in the same order as their players.
+        for index in (1..game_state.players.len()).rev() {
+            let (random, seed) = permuted_congruential_generator(game_state.seed);
+            game_state.seed = seed;
+            let other_index = random as usize % (index + 1);
+            game_state.players.as_mut().swap(index, other_index);
+            game_state.bombs.as_mut().swap(index, other_index);
+        }
+        game_state.next_player = game_state.players[0].clone();
+        Ok(game_state)
+    }
+
+    /// Start the game over on the same board: stones are cleared but blocks are kept, and
+    /// players get all their bombs back.
+    pub fn soft_reset(mut game_state: GameState<Player>) -> GameState<Player> {
+        for row in 0..game_state.board.height {
+            for col in 0..game_state.board.width {
+                let position = Coordinates::new(row, col);
+                if let Cell::Stone(_) = game_state.board.get_cell(&position) {
+                    game_state.board.update_cell(position, Cell::Empty);
+                }
+            }
+        }
+        for (_, bomb_states) in game_state.bombs.iter_mut() {
+            *bomb_states = [BombState::NotPlaced; NUM_OF_BOMBS_PER_PLAYER];
+        }
+        game_state.phase = GamePhase::Bomb;
+        game_state.winner = None;
+        game_state.is_draw = false;
+        game_state.next_player = game_state.players[0].clone();
+        game_state.forfeited = Default::default();
+        game_state.last_move = None;
+        game_state.winning_move = None;
+        game_state.move_index = 0;
+        game_state.undo_record = None;
+        game_state
+    }
+
+    /// Concede the game. The player leaves the turn rotation, and the last player left wins, which
+    /// is the opponent in two player games. Allowed in any phase and regardless of the turn.
+    pub fn forfeit(
+        game_state: GameState<Player>,
+        player: Player,
+    ) -> Result<GameState<Player>, GameError> {
+        if game_state.is_finished() {
+            return Err(GameError::GameAlreadyFinished);
+        }
+        if !game_state.is_player_in_game(&player) || game_state.has_forfeited(&player) {
+            return Err(GameError::NotInGame);
+        }
+
+        Ok(Self::leave_game(game_state, player))
+    }
+
+    /// Take an active player out of the game, see [`Game::forfeit`].
+    fn leave_game(mut game_state: GameState<Player>, player: Player) -> GameState<Player> {
+        if game_state.is_player_turn(&player) {
+            game_state.next_player = game_state.next_player().clone();
+        }
+        // Can't overflow: forfeited players are a subset of the players.
+        game_state.forfeited.force_push(player);
+        game_state.undo_record = None;
+
+        let active_players = game_state.active_players().cloned().collect::<Vec<_>>();
+        if let [winner] = active_players.as_slice() {
+            game_state.winner = Some(winner.clone());
+        } else if game_state.phase == GamePhase::Bomb && game_state.is_bomb_phase_over() {
+            game_state.change_game_phase(GamePhase::Play);
         }
+
+        game_state
     }
 
     /// Change game phase.
@@ -469,12 +1936,24 @@ impl<Player: PartialEq + Clone> Game<Player> {
 
     /// Drop a bomb. Called during bomb phase.
     pub fn drop_bomb(
-        mut game_state: GameState<Player>,
+        game_state: GameState<Player>,
         position: Coordinates,
         player: Player,
         player_secret: u64,
     ) -> Result<GameState<Player>, GameError> {
+        Self::drop_bomb_with_events(game_state, position, player, player_secret)
+            .map(|(game_state, _)| game_state)
+    }
+
+    /// Drop a bomb and list what happened.
+    pub fn drop_bomb_with_events(
+        mut game_state: GameState<Player>,
+        position: Coordinates,
+        player: Player,
+        player_secret: u64,
+    ) -> Result<(GameState<Player>, Vec<GameEvent<Player>>), GameError> {
         Self::can_drop_bomb(&game_state, &player, &position)?;
+        let mut events = Vec::new();
 
         let coordinate_hash = position.generate_hash(player_secret);
         let player_index = game_state.player_index(&player);
@@ -489,20 +1968,37 @@ impl<Player: PartialEq + Clone> Game<Player> {
             }
         }
 
-        if game_state.is_all_bomb_dropped() {
+        if game_state.is_bomb_phase_over() {
             game_state.change_game_phase(GamePhase::Play);
+            events.push(GameEvent::PhaseChanged(GamePhase::Play));
         }
+        game_state.move_index = game_state.move_index.saturating_add(1);
+        game_state.board.cool_down(game_state.move_index);
+        game_state.undo_record = None;
+        game_state = Game::check_draw(game_state);
 
-        Ok(game_state)
+        Ok((game_state, events))
     }
 
     pub fn detonate_bomb(
-        mut game_state: GameState<Player>,
+        game_state: GameState<Player>,
         player: Player,
         position: Coordinates,
         player_secret: u64,
     ) -> Result<GameState<Player>, GameError> {
+        Self::detonate_bomb_with_events(game_state, player, position, player_secret)
+            .map(|(game_state, _)| game_state)
+    }
+
+    /// Detonate a bomb and list what happened.
+    pub fn detonate_bomb_with_events(
+        mut game_state: GameState<Player>,
+        player: Player,
+        position: Coordinates,
+        player_secret: u64,
+    ) -> Result<(GameState<Player>, Vec<GameEvent<Player>>), GameError> {
         Self::can_detonate_bomb(&game_state, &player)?;
+        let board_before = game_state.board;
         let player_index = game_state.player_index(&player);
         let coordinate_hash = position.generate_hash(player_secret);
 
@@ -511,7 +2007,19 @@ impl<Player: PartialEq + Clone> Game<Player> {
         for entry in game_state.bombs[player_index as usize].1.iter_mut() {
             match entry {
                 BombState::Placed(ref placement_hash, _) if coordinate_hash == *placement_hash => {
-                    game_state.board.explode_bomb(position);
+                    // Scorched cells last for the given number of actions after this one.
+                    let scorched_until = game_state.config.scorch_duration.map(|duration| {
+                        game_state
+                            .move_index
+                            .saturating_add(1)
+                            .saturating_add(duration)
+                    });
+                    game_state.board.explode_bomb(
+                        position,
+                        game_state.config.explosion_mode,
+                        player_index,
+                        scorched_until,
+                    );
                     *entry = BombState::Detonated;
                     bomb_detonated = true;
                     break;
@@ -522,8 +2030,49 @@ impl<Player: PartialEq + Clone> Game<Player> {
 
         if bomb_detonated {
             game_state.next_player = game_state.next_player().clone();
+            game_state.move_index = game_state.move_index.saturating_add(1);
+            game_state.board.cool_down(game_state.move_index);
+            game_state.undo_record = None;
+            // Converted stones can complete squares.
+            game_state = Game::check_winner_player(game_state);
+            game_state = Game::check_draw(game_state);
+            game_state = Game::pass_stuck_players(game_state);
+
+            let changed_cells = board_before.changed_cells(&game_state.board);
+            let mut events = Vec::new();
+            events.push(GameEvent::BombExploded {
+                at: position,
+                cleared: changed_cells
+                    .iter()
+                    .filter(|(_, old_cell, new_cell)| {
+                        !matches!(old_cell, Cell::Scorched { .. })
+                            && matches!(new_cell, Cell::Empty | Cell::Scorched { .. })
+                    })
+                    .map(|(coords, _, _)| *coords)
+                    .collect(),
+            });
+            // The board is public, so it may hold stones of unknown players.
+            let player_at = |index: PlayerIndex| game_state.players.get(index as usize).cloned();
+            for (coords, old_cell, new_cell) in changed_cells {
+                match (old_cell, new_cell) {
+                    (Cell::Stone(owner), Cell::Empty | Cell::Scorched { .. }) => {
+                        if let Some(owner) = player_at(owner) {
+                            events.push(GameEvent::StoneDestroyed { owner, coords });
+                        }
+                    }
+                    (Cell::Stone(from), Cell::Stone(to)) => {
+                        if let (Some(from), Some(to)) = (player_at(from), player_at(to)) {
+                            events.push(GameEvent::StoneConverted { coords, from, to });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(winner) = &game_state.winner {
+                events.push(GameEvent::Winner(winner.clone()));
+            }
 
-            Ok(game_state)
+            Ok((game_state, events))
         } else {
             Err(GameError::InvalidBombPosition)
         }
@@ -531,36 +2080,47 @@ impl<Player: PartialEq + Clone> Game<Player> {
 
     /// Drop stone. Called during play phase.
     pub fn drop_stone(
-        mut game_state: GameState<Player>,
+        game_state: GameState<Player>,
         player: Player,
         side: Side,
         position: Position,
     ) -> Result<GameState<Player>, GameError> {
+        Self::drop_stone_with_events(game_state, player, side, position)
+            .map(|(game_state, _)| game_state)
+    }
+
+    /// Drop stone and list what happened.
+    pub fn drop_stone_with_events(
+        mut game_state: GameState<Player>,
+        player: Player,
+        side: Side,
+        position: Position,
+    ) -> Result<(GameState<Player>, Vec<GameEvent<Player>>), GameError> {
         Self::can_drop_stone(&game_state, &side, position, &player)?;
         let player_index = game_state.player_index(&player);
+        let board = &game_state.board;
+        let mut landing = None;
         match side {
             Side::North => {
                 let mut row = 0;
                 let mut stop = false;
-                while row < BOARD_HEIGHT && !stop {
+                while row < board.height && !stop {
                     let position = Coordinates::new(row, position);
-                    match game_state.board.get_cell(&position) {
+                    match board.get_cell(&position) {
                         // The stone is placed at the end if it's empty.
                         Cell::Empty => {
-                            if position.is_opposite_cell(side) {
-                                game_state
-                                    .board
-                                    .update_cell(position, Cell::Stone(player_index));
+                            if position.is_opposite_cell(side, board) {
+                                landing = Some(position);
                                 stop = true;
                             }
                         }
-                        // The stone is placed in the position previous to a block.
-                        Cell::Block => {
+                        // The stone is placed in the position previous to a block or scorched cell.
+                        Cell::Block | Cell::Scorched { .. } => {
                             if row > 0 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row.saturating_sub(1), position.col),
-                                    Cell::Stone(player_index),
-                                );
+                                landing = Some(Coordinates::new(
+                                    position.row.saturating_sub(1),
+                                    position.col,
+                                ));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -569,10 +2129,10 @@ impl<Player: PartialEq + Clone> Game<Player> {
                         // The stone is placed in the previous position of a stone.
                         Cell::Stone(_) => {
                             if row > 0 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row.saturating_sub(1), position.col),
-                                    Cell::Stone(player_index),
-                                );
+                                landing = Some(Coordinates::new(
+                                    position.row.saturating_sub(1),
+                                    position.col,
+                                ));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -583,27 +2143,22 @@ impl<Player: PartialEq + Clone> Game<Player> {
                 }
             }
             Side::East => {
-                let mut col = BOARD_WIDTH - 1;
+                let mut col = board.width - 1;
 
                 loop {
                     let position = Coordinates::new(position, col);
-                    match game_state.board.get_cell(&position) {
+                    match board.get_cell(&position) {
                         // The stone is placed at the end if it's empty.
                         Cell::Empty => {
-                            if position.is_opposite_cell(side) {
-                                game_state
-                                    .board
-                                    .update_cell(position, Cell::Stone(player_index));
+                            if position.is_opposite_cell(side, board) {
+                                landing = Some(position);
                                 break;
                             }
                         }
-                        // The stone is placed in the position previous to a block.
-                        Cell::Block => {
-                            if col < BOARD_WIDTH - 1 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row, position.col + 1),
-                                    Cell::Stone(player_index),
-                                );
+                        // The stone is placed in the position previous to a block or scorched cell.
+                        Cell::Block | Cell::Scorched { .. } => {
+                            if col < board.width - 1 {
+                                landing = Some(Coordinates::new(position.row, position.col + 1));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -611,11 +2166,8 @@ impl<Player: PartialEq + Clone> Game<Player> {
                         }
                         // The stone is placed in the previous position of a stone.
                         Cell::Stone(_) => {
-                            if col < BOARD_WIDTH - 1 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row, position.col + 1),
-                                    Cell::Stone(player_index),
-                                );
+                            if col < board.width - 1 {
+                                landing = Some(Coordinates::new(position.row, position.col + 1));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -629,27 +2181,22 @@ impl<Player: PartialEq + Clone> Game<Player> {
                 }
             }
             Side::South => {
-                let mut row = BOARD_HEIGHT - 1;
+                let mut row = board.height - 1;
 
                 loop {
                     let position = Coordinates::new(row, position);
-                    match game_state.board.get_cell(&position) {
+                    match board.get_cell(&position) {
                         // The stone is placed at the end if it's empty.
                         Cell::Empty => {
-                            if position.is_opposite_cell(side) {
-                                game_state
-                                    .board
-                                    .update_cell(position, Cell::Stone(player_index));
+                            if position.is_opposite_cell(side, board) {
+                                landing = Some(position);
                                 break;
                             }
                         }
-                        // The stone is placed in the position previous to a block.
-                        Cell::Block => {
-                            if row < BOARD_HEIGHT - 1 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row + 1, position.col),
-                                    Cell::Stone(player_index),
-                                );
+                        // The stone is placed in the position previous to a block or scorched cell.
+                        Cell::Block | Cell::Scorched { .. } => {
+                            if row < board.height - 1 {
+                                landing = Some(Coordinates::new(position.row + 1, position.col));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -657,11 +2204,8 @@ impl<Player: PartialEq + Clone> Game<Player> {
                         }
                         // The stone is placed in the previous position of a stone.
                         Cell::Stone(_) => {
-                            if row < BOARD_HEIGHT - 1 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row + 1, position.col),
-                                    Cell::Stone(player_index),
-                                );
+                            if row < board.height - 1 {
+                                landing = Some(Coordinates::new(position.row + 1, position.col));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -678,25 +2222,23 @@ impl<Player: PartialEq + Clone> Game<Player> {
             Side::West => {
                 let mut col = 0;
                 let mut stop = false;
-                while col < BOARD_WIDTH && !stop {
+                while col < board.width && !stop {
                     let position = Coordinates::new(position, col);
-                    match game_state.board.get_cell(&position) {
+                    match board.get_cell(&position) {
                         // The stone is placed at the end if it's empty.
                         Cell::Empty => {
-                            if position.is_opposite_cell(side) {
-                                game_state
-                                    .board
-                                    .update_cell(position, Cell::Stone(player_index));
+                            if position.is_opposite_cell(side, board) {
+                                landing = Some(position);
                                 stop = true;
                             }
                         }
-                        // The stone is placed in the position previous to a block.
-                        Cell::Block => {
+                        // The stone is placed in the position previous to a block or scorched cell.
+                        Cell::Block | Cell::Scorched { .. } => {
                             if col > 0 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row, position.col.saturating_sub(1)),
-                                    Cell::Stone(player_index),
-                                );
+                                landing = Some(Coordinates::new(
+                                    position.row,
+                                    position.col.saturating_sub(1),
+                                ));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -704,11 +2246,11 @@ impl<Player: PartialEq + Clone> Game<Player> {
                         }
                         // The stone is placed in the previous position of a stone.
                         Cell::Stone(_) => {
-                            if col < BOARD_WIDTH - 1 {
-                                game_state.board.update_cell(
-                                    Coordinates::new(position.row, position.col.saturating_sub(1)),
-                                    Cell::Stone(player_index),
-                                );
+                            if col > 0 {
+                                landing = Some(Coordinates::new(
+                                    position.row,
+                                    position.col.saturating_sub(1),
+                                ));
                             } else {
                                 return Err(GameError::InvalidStonePosition);
                             }
@@ -720,40 +2262,239 @@ impl<Player: PartialEq + Clone> Game<Player> {
             }
         }
 
-        game_state.last_move = Some(LastMove::new(player, side, position));
+        let landing = landing.ok_or(GameError::InvalidStonePosition)?;
+        game_state
+            .board
+            .update_cell(landing, Cell::Stone(player_index));
+
+        game_state.undo_record = Some(UndoRecord {
+            previous_move: game_state.last_move.take(),
+        });
+        let mut events = Vec::new();
+        events.push(GameEvent::StonePlaced {
+            player: player.clone(),
+            coords: landing,
+        });
+        game_state.last_move = Some(LastMove::new(player, side, position, landing));
         game_state.next_player = game_state.next_player().clone();
+        game_state.move_index = game_state.move_index.saturating_add(1);
+        if game_state.board.cool_down(game_state.move_index) {
+            // Undo only empties the landing cell, it can't bring scorched cells back.
+            game_state.undo_record = None;
+        }
         game_state = Game::check_winner_player(game_state);
+        game_state = Game::check_draw(game_state);
+        game_state = Game::pass_stuck_players(game_state);
+        if let Some(winner) = &game_state.winner {
+            events.push(GameEvent::Winner(winner.clone()));
+        }
+
+        Ok((game_state, events))
+    }
+
+    /// Take back the last action, as long as it was a stone drop. Only one move can be taken
+    /// back in a row.
+    pub fn undo(mut game_state: GameState<Player>) -> Result<GameState<Player>, GameError> {
+        let undo_record = game_state
+            .undo_record
+            .take()
+            .ok_or(GameError::UndoNotSupported)?;
+        let last_move = game_state
+            .last_move
+            .take()
+            .ok_or(GameError::UndoNotSupported)?;
+
+        game_state.board.update_cell(last_move.landing, Cell::Empty);
+        game_state.next_player = last_move.player;
+        game_state.last_move = undo_record.previous_move;
+        game_state.move_index = game_state.move_index.saturating_sub(1);
+        game_state.winner = None;
+        game_state.winning_move = None;
+        game_state.is_draw = false;
 
         Ok(game_state)
     }
 
+    /// Drop stone and report the outcome of the move.
+    pub fn drop_stone_reported(
+        game_state: GameState<Player>,
+        player: Player,
+        side: Side,
+        position: Position,
+    ) -> Result<(GameState<Player>, MoveReport<Player>), GameError> {
+        let game_state = Self::drop_stone(game_state, player, side, position)?;
+        let landing = game_state
+            .last_move
+            .as_ref()
+            .map(|last_move| last_move.landing)
+            .expect("last move to be set after dropping a stone");
+        let report = MoveReport {
+            landing,
+            next_player: game_state.next_player.clone(),
+            winner: game_state.winner.clone(),
+            is_draw: game_state.is_draw,
+        };
+
+        Ok((game_state, report))
+    }
+
+    /// Every side and position from which the player can drop a stone right now.
+    pub fn valid_stone_moves(
+        game_state: &GameState<Player>,
+        player: &Player,
+    ) -> Vec<(Side, Position)> {
+        game_state
+            .board
+            .drop_positions()
+            .filter(|(side, position)| {
+                Self::can_drop_stone(game_state, side, *position, player).is_ok()
+            })
+            .collect()
+    }
+
+    /// Every cell where the player can drop a bomb right now.
+    pub fn valid_bomb_positions(
+        game_state: &GameState<Player>,
+        player: &Player,
+    ) -> Vec<Coordinates> {
+        (0..game_state.board.height)
+            .flat_map(|row| (0..game_state.board.width).map(move |col| Coordinates::new(row, col)))
+            .filter(|position| Self::can_drop_bomb(game_state, player, position).is_ok())
+            .collect()
+    }
+
+    /// Apply a single action.
+    pub fn apply(
+        game_state: GameState<Player>,
+        action: GameAction<Player>,
+    ) -> Result<GameState<Player>, GameError> {
+        match action {
+            GameAction::DropBomb {
+                player,
+                coords,
+                secret,
+            } => Self::drop_bomb(game_state, coords, player, secret),
+            GameAction::DropStone {
+                player,
+                side,
+                position,
+            } => Self::drop_stone(game_state, player, side, position),
+            GameAction::Detonate {
+                player,
+                coords,
+                secret,
+            } => Self::detonate_bomb(game_state, player, coords, secret),
+        }
+    }
+
+    /// Apply a single action, only if the game is at the given move index. Protects against
+    /// actions submitted twice or built from an outdated state.
+    pub fn apply_expecting(
+        game_state: GameState<Player>,
+        action: GameAction<Player>,
+        expected_move_index: u32,
+    ) -> Result<GameState<Player>, GameError> {
+        if game_state.expected_next_move_index() != expected_move_index {
+            return Err(GameError::StaleMove);
+        }
+        Self::apply(game_state, action)
+    }
+
+    /// Apply a sequence of actions, stopping at the first one that fails.
+    ///
+    /// Returns the state after the last successful action, together with the index and error of
+    /// the failing action, if any.
+    pub fn apply_many(
+        mut game_state: GameState<Player>,
+        actions: &[GameAction<Player>],
+    ) -> (GameState<Player>, Result<(), (usize, GameError)>) {
+        for (index, action) in actions.iter().enumerate() {
+            match Self::apply(game_state.clone(), action.clone()) {
+                Ok(new_state) => game_state = new_state,
+                Err(error) => return (game_state, Err((index, error))),
+            }
+        }
+
+        (game_state, Ok(()))
+    }
+
+    /// Reconstruct the state of a game after its first `to_move` actions.
+    pub fn rewind(
+        initial: GameState<Player>,
+        history: &[GameAction<Player>],
+        to_move: u32,
+    ) -> Result<GameState<Player>, GameError> {
+        history
+            .get(..to_move as usize)
+            .ok_or(GameError::InvalidMoveIndex)?
+            .iter()
+            .try_fold(initial, |game_state, action| {
+                Self::apply(game_state, action.clone())
+            })
+    }
+
+    /// Apply every move of a log in order. On error, returns the index of the first move that
+    /// failed along with its error.
+    ///
+    /// The log is replayed on top of the initial state rather than on a game created from the
+    /// players and seed: the players, board configuration and rules of the game are not part of
+    /// the log, and a replay without them would diverge from the original game.
+    pub fn replay(
+        initial: GameState<Player>,
+        moves: &[Move<Player>],
+    ) -> Result<GameState<Player>, (usize, GameError)> {
+        let (game_state, result) = Self::apply_many(initial, moves);
+        result.map(|_| game_state)
+    }
+
     fn check_winner_player(mut game_state: GameState<Player>) -> GameState<Player> {
         if game_state.winner.is_some() {
             return game_state;
         }
 
-        let board = &game_state.board;
-        let mut squares = [0; NUM_OF_PLAYERS];
-
-        for row in 0..BOARD_HEIGHT - 1 {
-            for col in 0..BOARD_WIDTH - 1 {
-                let cell = board.get_cell(&Coordinates::new(row, col));
-                if let Cell::Stone(player_index) = cell {
-                    if cell == board.get_cell(&Coordinates::new(row, col + 1))
-                        && cell == board.get_cell(&Coordinates::new(row + 1, col))
-                        && cell == board.get_cell(&Coordinates::new(row + 1, col + 1))
-                    {
-                        squares[player_index as usize] += 1;
-                        if squares[player_index as usize] >= 3 {
-                            let winner = game_state.players[player_index as usize].clone();
-                            game_state.winner = Some(winner);
-                            break;
-                        }
-                    }
+        if let Some(player_index) = game_state
+            .board
+            .compute_winner(&game_state.players, NUM_OF_SQUARES_TO_WIN)
+        {
+            let winner = game_state.players[player_index as usize].clone();
+            game_state.winner = Some(winner);
+            game_state.winning_move = game_state.last_move.clone();
+        }
+
+        game_state
+    }
+
+    /// Apply the [`StuckPlayerRule`] until the player to move has something to do. Some player is
+    /// always left with a move, otherwise the game would have ended in a stalemate.
+    fn pass_stuck_players(mut game_state: GameState<Player>) -> GameState<Player> {
+        for _ in 0..game_state.players.len() {
+            let player = game_state.next_player.clone();
+            if game_state.phase != GamePhase::Play
+                || game_state.is_finished()
+                || game_state.has_any_legal_move_for(&player)
+            {
+                break;
+            }
+            match game_state.config.stuck_player_rule {
+                StuckPlayerRule::SkipTurn => {
+                    game_state.next_player = game_state.next_player().clone();
                 }
+                StuckPlayerRule::Forfeit => game_state = Game::leave_game(game_state, player),
             }
         }
 
         game_state
     }
+
+    fn check_draw(mut game_state: GameState<Player>) -> GameState<Player> {
+        let is_out_of_moves = matches!(
+            game_state.config.max_moves,
+            Some(max_moves) if game_state.move_index >= max_moves
+        );
+        if game_state.winner.is_none() && (is_out_of_moves || game_state.is_stalemate()) {
+            game_state.is_draw = true;
+        }
+
+        game_state
+    }
 }
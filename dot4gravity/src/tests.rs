@@ -15,15 +15,29 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::*;
+use scale_info::prelude::vec;
 
 const ALICE: u8 = 11;
 const BOB: u8 = 22;
 const CHARLIE: u8 = 33;
+const DAVE: u8 = 44;
 const TEST_COORDINATES: Coordinates = Coordinates::new(0, 0);
 
 const SECRET_1: u64 = 19;
 const SECRET_2: u64 = 23;
 
+const GENESIS_BLOCK: BlockNumber = 100;
+
+fn board_from_cells(cells: [[Cell; BOARD_WIDTH as usize]; BOARD_HEIGHT as usize]) -> Board {
+    let mut board = Board::new();
+    for (row, row_cells) in cells.iter().enumerate() {
+        for (col, cell) in row_cells.iter().enumerate() {
+            board.update_cell(Coordinates::new(row as u8, col as u8), *cell);
+        }
+    }
+    board
+}
+
 #[test]
 fn should_create_a_new_board() {
     fn is_empty(board: &Board) -> bool {
@@ -39,8 +53,8 @@ fn should_create_a_new_board() {
     }
 
     let board = Board::new();
-    assert_eq!(board.cells.len() as u8, BOARD_HEIGHT);
-    assert_eq!(board.cells[0].len() as u8, BOARD_WIDTH);
+    assert_eq!(board.height(), BOARD_HEIGHT);
+    assert_eq!(board.width(), BOARD_WIDTH);
     assert!(is_empty(&board))
 }
 
@@ -62,10 +76,396 @@ fn board_cell_can_be_changed() {
     );
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn should_render_board_with_glyphs() {
+    let mut board = Board::new();
+    board.update_cell(Coordinates::new(0, 0), Cell::Block);
+    board.update_cell(Coordinates::new(0, 9), Cell::Stone(0));
+    board.update_cell(Coordinates::new(9, 5), Cell::Stone(1));
+
+    assert_eq!(
+        board.render_with(&CellGlyphs::default()),
+        [
+            "#........o",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            ".....x....",
+        ]
+        .join("\n")
+    );
+
+    let glyphs = CellGlyphs {
+        empty: ' ',
+        block: '█',
+        stones: ['🔴', '🔵', '🟢', '🟡'],
+        scorched: '🔥',
+    };
+    assert_eq!(
+        board.render_with(&glyphs),
+        [
+            "█        🔴",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "     🔵    ",
+        ]
+        .join("\n")
+    );
+    assert_eq!(board.to_string(), board.render_with(&CellGlyphs::default()));
+
+    let stones = (0..4)
+        .map(|player_index| CellGlyphs::default().glyph(Cell::Stone(player_index)))
+        .collect::<String>();
+    assert_eq!(stones, "ox+@");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn should_render_game_state() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        Some(BoardConfig {
+            width: 3,
+            height: 2,
+            num_blocks: 0,
+        }),
+    )
+    .unwrap();
+    state
+        .board
+        .update_cell(Coordinates::new(1, 2), Cell::Stone(1));
+    assert_eq!(state.render(), "...\n..x\nphase: Bomb\nnext player: 11");
+
+    state.winner = Some(BOB);
+    assert_eq!(state.render(), "...\n..x\nphase: Bomb\nwinner: 22");
+}
+
+#[test]
+fn should_round_trip_flat_game_state() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state
+        .board
+        .update_cell(Coordinates::new(0, 1), Cell::Stone(0));
+    state
+        .board
+        .update_cell(Coordinates::new(9, 8), Cell::Stone(1));
+    state.phase = GamePhase::Play;
+    state.next_player = BOB;
+
+    let flat = state.to_flat();
+    assert_eq!(flat.width, BOARD_WIDTH);
+    assert_eq!(flat.height, BOARD_HEIGHT);
+    assert_eq!(flat.width as usize * flat.height as usize, flat.cells.len());
+    assert_eq!(flat.cells[1], 2);
+    assert_eq!(flat.cells[98], 3);
+    assert_eq!(flat.phase, 1);
+    assert_eq!(flat.players, vec![ALICE, BOB]);
+    assert_eq!(flat.next_player, BOB);
+    assert_eq!(flat.winner, None);
+    assert_eq!(
+        Board::from_flat(&flat.cells, flat.width, flat.height),
+        Some(state.board)
+    );
+
+    let rebuilt = GameState::from_flat(flat.clone()).unwrap();
+    assert_eq!(rebuilt.board, state.board);
+    assert_eq!(rebuilt.phase, GamePhase::Play);
+    assert_eq!(rebuilt.players, state.players);
+    assert_eq!(rebuilt.to_flat(), flat);
+}
+
+#[test]
+fn should_not_rebuild_game_from_inconsistent_flat_state() {
+    let state = Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let flat = state.to_flat();
+    assert!(GameState::from_flat(flat.clone()).is_some());
+
+    let mut unknown_owner = flat.clone();
+    unknown_owner.cells[0] = 4;
+    assert_eq!(GameState::from_flat(unknown_owner), None);
+
+    let mut unknown_cell = flat.clone();
+    unknown_cell.cells[0] = 7;
+    assert_eq!(
+        Board::from_flat(&unknown_cell.cells, unknown_cell.width, unknown_cell.height),
+        None
+    );
+    assert_eq!(GameState::from_flat(unknown_cell), None);
+
+    let mut unknown_next_player = flat.clone();
+    unknown_next_player.next_player = CHARLIE;
+    assert_eq!(GameState::from_flat(unknown_next_player), None);
+
+    let mut unknown_winner = flat.clone();
+    unknown_winner.winner = Some(CHARLIE);
+    assert_eq!(GameState::from_flat(unknown_winner), None);
+
+    let mut unknown_phase = flat;
+    unknown_phase.phase = 2;
+    assert_eq!(GameState::from_flat(unknown_phase), None);
+}
+
+#[test]
+fn should_not_decode_board_with_invalid_size() {
+    let board = Board::new();
+    let encoded = board.encode();
+    assert_eq!(Board::decode(&mut encoded.as_slice()), Ok(board));
+
+    for (width, height) in [(0, 10), (16, 10), (10, 1), (10, 255)] {
+        let mut tampered = encoded.clone();
+        let len = tampered.len();
+        tampered[len - 2] = width;
+        tampered[len - 1] = height;
+        assert!(Board::decode(&mut tampered.as_slice()).is_err());
+    }
+}
+
+#[test]
+fn should_not_build_board_from_flat_cells_with_wrong_size() {
+    assert_eq!(Board::from_flat(&[0; 99], 10, 10), None);
+    assert_eq!(Board::from_flat(&[0; 101], 10, 10), None);
+    assert_eq!(Board::from_flat(&[0; 256], 16, 16), None);
+    assert_eq!(Board::from_flat(&[0; 1], 1, 1), None);
+}
+
+#[test]
+fn should_round_trip_minimal_encoding() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state
+        .board
+        .update_cell(Coordinates::new(0, 1), Cell::Stone(0));
+    state
+        .board
+        .update_cell(Coordinates::new(9, 9), Cell::Stone(1));
+    state.phase = GamePhase::Play;
+    state.next_player = BOB;
+
+    let encoded = state.encode_minimal();
+    assert_eq!(
+        DisplayState::decode_minimal(&encoded),
+        Some(DisplayState {
+            board: state.board,
+            phase: GamePhase::Play,
+            next_player_index: state.player_index(&BOB),
+        })
+    );
+    assert_eq!(
+        encoded.len(),
+        (BOARD_WIDTH as usize * BOARD_HEIGHT as usize) / 2 + 4
+    );
+    assert!(encoded.len() < state.encode().len());
+    assert_eq!(DisplayState::decode_minimal(&encoded[1..]), None);
+}
+
+#[test]
+fn should_list_changed_cells_after_a_move() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+
+    let before = state.board;
+    let state = Game::drop_stone(state, ALICE, Side::North, 3).unwrap();
+    assert_eq!(
+        before.changed_cells(&state.board),
+        vec![(
+            Coordinates::new(9, 3),
+            Cell::Empty,
+            Cell::Stone(state.player_index(&ALICE))
+        )]
+    );
+    assert!(state.board.changed_cells(&state.board).is_empty());
+}
+
+#[test]
+fn should_list_changed_cells_after_an_explosion() {
+    let mut board = Board::new();
+    board.update_cell(Coordinates::new(4, 4), Cell::Stone(0));
+    board.update_cell(Coordinates::new(5, 5), Cell::Stone(1));
+    board.update_cell(Coordinates::new(6, 6), Cell::Block);
+
+    let before = board;
+    board.explode_bomb(Coordinates::new(5, 5), ExplosionMode::Clear, 0, None);
+    assert_eq!(
+        before.changed_cells(&board),
+        vec![
+            (Coordinates::new(4, 4), Cell::Stone(0), Cell::Empty),
+            (Coordinates::new(5, 5), Cell::Stone(1), Cell::Empty),
+        ]
+    );
+}
+
+#[test]
+fn blast_cells_should_exclude_blocks() {
+    let mut board = Board::new();
+    board.update_cell(Coordinates::new(4, 4), Cell::Block);
+    board.update_cell(Coordinates::new(6, 5), Cell::Block);
+    board.update_cell(Coordinates::new(5, 6), Cell::Stone(0));
+
+    assert_eq!(
+        board.blast_cells(Coordinates::new(5, 5)),
+        vec![
+            Coordinates::new(5, 5),
+            Coordinates::new(5, 4),
+            Coordinates::new(6, 4),
+            Coordinates::new(6, 6),
+            Coordinates::new(5, 6),
+            Coordinates::new(4, 6),
+            Coordinates::new(4, 5),
+        ]
+    );
+}
+
+#[test]
+fn blast_cells_should_exclude_cells_outside_the_board() {
+    let board = Board::new();
+    assert_eq!(
+        board.blast_cells(Coordinates::new(0, 0)),
+        vec![
+            Coordinates::new(0, 0),
+            Coordinates::new(1, 0),
+            Coordinates::new(1, 1),
+            Coordinates::new(0, 1),
+        ]
+    );
+}
+
+#[test]
+fn explosions_at_the_edges_should_only_clear_cells_inside_the_board() {
+    for (center, cleared_cells) in [
+        (Coordinates::new(0, 0), 4),
+        (Coordinates::new(9, 9), 4),
+        (Coordinates::new(0, 5), 6),
+        (Coordinates::new(9, 5), 6),
+        (Coordinates::new(5, 0), 6),
+        (Coordinates::new(5, 9), 6),
+    ] {
+        let mut board = Board::new();
+        for row in 0..BOARD_HEIGHT {
+            for col in 0..BOARD_WIDTH {
+                board.update_cell(Coordinates::new(row, col), Cell::Stone(0));
+            }
+        }
+        let before = board;
+        board.explode_bomb(center, ExplosionMode::Clear, 0, None);
+
+        let changed_cells = before.changed_cells(&board);
+        assert_eq!(changed_cells.len(), cleared_cells);
+        for (position, _, cell) in changed_cells {
+            assert_eq!(cell, Cell::Empty);
+            assert!(position.row.abs_diff(center.row) <= 1);
+            assert!(position.col.abs_diff(center.col) <= 1);
+        }
+    }
+}
+
+#[test]
+fn coordinates_should_be_classified_into_quadrants_and_halves() {
+    let board = Board::new();
+    for (coordinates, quadrant, row_half, col_half) in [
+        // Corners
+        (
+            Coordinates::new(0, 0),
+            Quadrant::NorthWest,
+            Half::Lower,
+            Half::Lower,
+        ),
+        (
+            Coordinates::new(0, 9),
+            Quadrant::NorthEast,
+            Half::Lower,
+            Half::Upper,
+        ),
+        (
+            Coordinates::new(9, 0),
+            Quadrant::SouthWest,
+            Half::Upper,
+            Half::Lower,
+        ),
+        (
+            Coordinates::new(9, 9),
+            Quadrant::SouthEast,
+            Half::Upper,
+            Half::Upper,
+        ),
+        // Edges
+        (
+            Coordinates::new(0, 4),
+            Quadrant::NorthWest,
+            Half::Lower,
+            Half::Lower,
+        ),
+        (
+            Coordinates::new(5, 9),
+            Quadrant::SouthEast,
+            Half::Upper,
+            Half::Upper,
+        ),
+        (
+            Coordinates::new(9, 5),
+            Quadrant::SouthEast,
+            Half::Upper,
+            Half::Upper,
+        ),
+        (
+            Coordinates::new(4, 0),
+            Quadrant::NorthWest,
+            Half::Lower,
+            Half::Lower,
+        ),
+        // Center
+        (
+            Coordinates::new(4, 4),
+            Quadrant::NorthWest,
+            Half::Lower,
+            Half::Lower,
+        ),
+        (
+            Coordinates::new(4, 5),
+            Quadrant::NorthEast,
+            Half::Lower,
+            Half::Upper,
+        ),
+        (
+            Coordinates::new(5, 4),
+            Quadrant::SouthWest,
+            Half::Upper,
+            Half::Lower,
+        ),
+        (
+            Coordinates::new(5, 5),
+            Quadrant::SouthEast,
+            Half::Upper,
+            Half::Upper,
+        ),
+    ] {
+        assert_eq!(coordinates.board_quadrant(&board), quadrant);
+        assert_eq!(coordinates.half(Axis::Row, &board), row_half);
+        assert_eq!(coordinates.half(Axis::Col, &board), col_half);
+    }
+}
+
 #[test]
 fn should_create_new_game() {
-    let game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    let computed_from_initial_seed = 46_384;
+    let game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let computed_from_initial_seed = 9_872_450_590_957_409_814;
     assert_eq!(game_state.seed, computed_from_initial_seed);
     assert_eq!(
         game_state.phase,
@@ -74,7 +474,7 @@ fn should_create_new_game() {
     );
     assert_eq!(game_state.winner, None, "No player should have won yet");
     assert_eq!(game_state.next_player, ALICE);
-    assert_eq!(game_state.bombs.len(), NUM_OF_PLAYERS);
+    assert_eq!(game_state.bombs.len(), 2);
     assert_eq!(
         game_state.get_player_bombs(&ALICE),
         Some(NUM_OF_BOMBS_PER_PLAYER as u8),
@@ -95,129 +495,1222 @@ fn should_create_new_game() {
 }
 
 #[test]
-fn should_create_new_game_with_random_blocks() {
-    let blocks = |board: Board| -> u8 {
-        let mut block_count = 0;
-        board.cells.iter().for_each(|row| {
-            row.iter().for_each(|cell| {
-                if let Cell::Block = cell {
-                    block_count += 1;
-                }
-            })
-        });
-        block_count
-    };
-
-    let (mut seed_1, mut seed_2) = (123, 456);
-    for _ in 0..20 {
-        let game_1 = Game::new_game(ALICE, BOB, Some(seed_1));
-        let game_2 = Game::new_game(ALICE, BOB, Some(seed_2));
-        assert_ne!(game_1.board, game_2.board);
-        assert_eq!(blocks(game_1.board), NUM_OF_BLOCKS);
-        assert_eq!(blocks(game_2.board), NUM_OF_BLOCKS);
-        assert_ne!(seed_1, game_1.seed, "seed 1 should be updated");
-        assert_ne!(seed_2, game_2.seed, "seed 2 should be updated");
-        seed_1 = game_1.seed;
-        seed_2 = game_2.seed;
-    }
+fn should_not_create_new_game_with_duplicated_player() {
+    assert_eq!(
+        Game::new_game(vec![ALICE, ALICE], Some(INITIAL_SEED), GENESIS_BLOCK, None),
+        Err(GameError::DuplicatedPlayer)
+    );
+    assert_eq!(
+        Game::new_game_with_canonical_order(
+            vec![BOB, BOB],
+            Some(INITIAL_SEED),
+            GENESIS_BLOCK,
+            None
+        ),
+        Err(GameError::DuplicatedPlayer)
+    );
 }
 
 #[test]
-fn should_create_new_game_with_deterministic_blocks_with_fixed_seed() {
-    let seed = 7357;
-    for _ in 0..20 {
-        let game_1 = Game::new_game(ALICE, BOB, Some(seed));
-        let game_2 = Game::new_game(ALICE, BOB, Some(seed));
-        assert_eq!(game_1.board, game_2.board);
-    }
+fn should_not_create_new_game_with_invalid_number_of_players() {
+    assert_eq!(
+        Game::new_game(vec![ALICE], Some(INITIAL_SEED), GENESIS_BLOCK, None),
+        Err(GameError::InvalidNumberOfPlayers)
+    );
+    assert_eq!(
+        Game::new_game(
+            vec![ALICE, BOB, CHARLIE, DAVE, 55],
+            Some(INITIAL_SEED),
+            GENESIS_BLOCK,
+            None
+        ),
+        Err(GameError::InvalidNumberOfPlayers)
+    );
+    assert_eq!(
+        Game::new_game(
+            vec![ALICE, BOB, CHARLIE, BOB],
+            Some(INITIAL_SEED),
+            GENESIS_BLOCK,
+            None
+        ),
+        Err(GameError::DuplicatedPlayer)
+    );
 }
 
 #[test]
-fn a_player_cannot_drop_bomb_in_play_phase() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.phase = GamePhase::Play;
-    let result = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1);
-    assert_eq!(result, Err(GameError::DroppedBombOutsideBombPhase));
+fn should_show_board_from_player_perspective() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state
+        .board
+        .update_cell(Coordinates::new(0, 1), Cell::Stone(0));
+    state
+        .board
+        .update_cell(Coordinates::new(9, 8), Cell::Stone(1));
+
+    assert_eq!(state.as_perspective(&ALICE), Ok(state.board));
+
+    let bob_view = state.as_perspective(&BOB).unwrap();
+    assert_eq!(bob_view.get_cell(&Coordinates::new(9, 8)), Cell::Stone(0));
+    assert_eq!(bob_view.get_cell(&Coordinates::new(0, 1)), Cell::Stone(1));
+    assert_eq!(
+        bob_view.changed_cells(&state.board).len(),
+        2,
+        "Only stones should be remapped"
+    );
+
+    let mut remapped = state.clone();
+    remapped.board = bob_view;
+    assert_eq!(remapped.as_perspective(&BOB), Ok(state.board));
+    assert_eq!(state.as_perspective(&CHARLIE), Err(GameError::NotInGame));
 }
 
 #[test]
-fn a_player_cannot_drop_bomb_if_already_dropped_all() {
-    for _ in 0..NUM_OF_BOMBS_PER_PLAYER {
-        let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-        game_state.bombs = [
-            (ALICE, [BombState::Detonated; NUM_OF_BOMBS_PER_PLAYER]),
-            (BOB, [BombState::Detonated; NUM_OF_BOMBS_PER_PLAYER]),
-        ];
-        assert_eq!(
-            Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1),
-            Err(GameError::NoMoreBombsAvailable)
-        );
+fn should_list_valid_stone_moves() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    assert!(
+        Game::valid_stone_moves(&state, &ALICE).is_empty(),
+        "No stones can be dropped in bomb phase"
+    );
 
-        assert_eq!(
-            Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2),
-            Err(GameError::NoMoreBombsAvailable)
-        );
+    state.phase = GamePhase::Play;
+    let moves = Game::valid_stone_moves(&state, &ALICE);
+    assert_eq!(
+        moves.len(),
+        2 * BOARD_WIDTH as usize + 2 * BOARD_HEIGHT as usize
+    );
+    assert!(Game::valid_stone_moves(&state, &BOB).is_empty());
+
+    state.board.update_cell(Coordinates::new(0, 3), Cell::Block);
+    state
+        .board
+        .update_cell(Coordinates::new(9, 8), Cell::Stone(1));
+    let moves = Game::valid_stone_moves(&state, &ALICE);
+    assert_eq!(
+        moves.len(),
+        2 * BOARD_WIDTH as usize + 2 * BOARD_HEIGHT as usize - 2
+    );
+    for (side, position) in [(Side::North, 3), (Side::South, 8)] {
+        assert!(!moves.contains(&(side, position)));
+    }
+    for (side, position) in moves {
+        assert!(Game::drop_stone(state.clone(), ALICE, side, position).is_ok());
     }
+
+    state.winner = Some(BOB);
+    assert!(Game::valid_stone_moves(&state, &ALICE).is_empty());
 }
 
 #[test]
-fn a_player_cannot_drop_bomb_if_game_already_finished() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.winner = Some(ALICE);
+fn should_list_valid_bomb_positions() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.board.update_cell(Coordinates::new(4, 4), Cell::Block);
+    let all_cells = BOARD_WIDTH as usize * BOARD_HEIGHT as usize;
     assert_eq!(
-        Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_1),
-        Err(GameError::GameAlreadyFinished),
-    )
-}
+        Game::valid_bomb_positions(&state, &ALICE).len(),
+        all_cells - 1
+    );
 
-#[test]
-fn dropping_bomb_should_not_update_last_move() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
+    state = Game::drop_bomb(state, Coordinates::new(1, 1), BOB, SECRET_2).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(2, 2), ALICE, SECRET_1).unwrap();
+    let positions = Game::valid_bomb_positions(&state, &ALICE);
+    assert_eq!(positions.len(), all_cells - 2);
+    assert!(!positions.contains(&Coordinates::new(4, 4)));
+    assert!(!positions.contains(&Coordinates::new(2, 2)));
+    assert!(
+        positions.contains(&Coordinates::new(1, 1)),
+        "Cells with other players' bombs are still valid"
+    );
 
-    assert!(Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_2).is_ok());
-    assert_eq!(game_state.last_move, None);
+    state.config.bomb_edge_margin = 1;
+    assert_eq!(
+        Game::valid_bomb_positions(&state, &ALICE).len(),
+        (BOARD_WIDTH as usize - 2) * (BOARD_HEIGHT as usize - 2) - 2
+    );
+
+    state = Game::drop_bomb(state, Coordinates::new(3, 3), ALICE, SECRET_1).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(3, 4), ALICE, SECRET_1).unwrap();
+    assert!(Game::valid_bomb_positions(&state, &ALICE).is_empty());
+    assert!(!Game::valid_bomb_positions(&state, &BOB).is_empty());
+    assert!(Game::valid_bomb_positions(&state, &CHARLIE).is_empty());
 }
 
 #[test]
-fn a_player_drops_a_bomb() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
+fn should_report_start_fairness() {
+    let new_game =
+        |seed| Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
 
-    let player_bombs = game_state.get_player_bombs(&ALICE).unwrap();
-    assert_eq!(player_bombs, NUM_OF_BOMBS_PER_PLAYER as u8);
+    let balanced = new_game(18).start_fairness();
+    assert_eq!(
+        balanced,
+        FairnessReport {
+            block_balance: 1,
+            lane_openness: [10, 9, 10, 10],
+        }
+    );
 
-    let game_state = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1).unwrap();
+    let lopsided = new_game(37);
     assert_eq!(
-        game_state.get_player_bombs(&ALICE).unwrap(),
-        player_bombs - 1,
-        "The player should have one bomb less available for dropping"
+        lopsided.start_fairness(),
+        FairnessReport {
+            block_balance: 6,
+            lane_openness: [7, 10, 10, 7],
+        }
     );
+
+    let mut state = lopsided;
+    state.phase = GamePhase::Play;
+    for (index, side) in [Side::North, Side::East, Side::South, Side::West]
+        .into_iter()
+        .enumerate()
+    {
+        let open_lanes = Game::valid_stone_moves(&state, &ALICE)
+            .into_iter()
+            .filter(|(lane_side, _)| *lane_side == side)
+            .count();
+        assert_eq!(
+            state.start_fairness().lane_openness[index] as usize,
+            open_lanes
+        );
+    }
+
+    let mut empty = new_game(18);
+    empty.board = Board::new();
     assert_eq!(
-        game_state.bombs[0],
-        (
-            ALICE,
-            [
-                BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1),
-                BombState::NotPlaced,
-                BombState::NotPlaced
-            ]
-        )
-    )
+        empty.start_fairness(),
+        FairnessReport {
+            block_balance: 0,
+            lane_openness: [10; 4],
+        }
+    );
 }
 
 #[test]
-fn a_cell_can_hold_one_or_more_bombs_from_different_players() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
+fn should_list_available_actions() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state = Game::drop_bomb(state, Coordinates::new(2, 3), ALICE, SECRET_1).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(7, 7), ALICE, SECRET_2).unwrap();
+    assert!(
+        state.available_actions(&ALICE).is_empty(),
+        "Bomb drops can't be listed"
+    );
 
-    let drop_bomb_result = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1);
-    assert!(drop_bomb_result.is_ok());
-    game_state = drop_bomb_result.unwrap();
+    state.phase = GamePhase::Play;
+    state = Game::detonate_bomb(state, ALICE, Coordinates::new(7, 7), SECRET_2).unwrap();
+    state = Game::drop_stone(state, BOB, Side::North, 0).unwrap();
+    state.board.update_cell(Coordinates::new(0, 3), Cell::Block);
+
+    let mut expected = Game::valid_stone_moves(&state, &ALICE)
+        .into_iter()
+        .map(|(side, position)| GameAction::DropStone {
+            player: ALICE,
+            side,
+            position,
+        })
+        .collect::<Vec<_>>();
+    // The block closes North 3, and Bob's stone in the corner closes South 0 and West 9.
+    assert_eq!(expected.len(), 4 * 10 - 3);
+    expected.push(GameAction::Detonate {
+        player: ALICE,
+        coords: Coordinates::new(2, 3),
+        secret: SECRET_1,
+    });
+    let actions = state.available_actions(&ALICE);
+    assert_eq!(actions, expected);
+    for action in actions {
+        assert!(Game::apply(state.clone(), action).is_ok());
+    }
+    assert!(
+        state.available_actions(&BOB).is_empty(),
+        "It's not Bob's turn"
+    );
+}
 
-    assert_eq!(
+#[test]
+fn legal_move_count_should_match_listed_moves() {
+    let assert_count_matches = |state: &GameState<u8>| {
+        for player in [ALICE, BOB, CHARLIE] {
+            assert_eq!(
+                state.legal_move_count(&player),
+                Game::valid_stone_moves(state, &player).len()
+                    + Game::valid_bomb_positions(state, &player).len()
+            );
+        }
+    };
+
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    assert_count_matches(&state);
+    state = Game::drop_bomb(state, Coordinates::new(5, 5), ALICE, SECRET_1).unwrap();
+    state.config.bomb_edge_margin = 2;
+    assert_count_matches(&state);
+
+    state.phase = GamePhase::Play;
+    assert_count_matches(&state);
+    state = Game::drop_stone(state, ALICE, Side::North, 2).unwrap();
+    state
+        .board
+        .update_cell(Coordinates::new(0, 6), Cell::Stone(0));
+    assert_count_matches(&state);
+
+    let small = Game::new_game(
+        vec![ALICE, BOB],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        Some(BoardConfig {
+            width: 4,
+            height: 3,
+            num_blocks: 5,
+        }),
+    )
+    .unwrap();
+    assert_count_matches(&small);
+    assert_eq!(small.legal_move_count(&ALICE), 7);
+}
+
+#[test]
+fn should_end_in_a_draw_when_no_move_is_left() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.phase = GamePhase::Play;
+    // A checkerboard has no squares.
+    for row in 0..BOARD_HEIGHT {
+        for col in 0..BOARD_WIDTH {
+            state
+                .board
+                .update_cell(Coordinates::new(row, col), Cell::Stone((row + col) % 2));
+        }
+    }
+    state.board.update_cell(Coordinates::new(0, 5), Cell::Empty);
+    assert!(!state.is_stalemate());
+
+    let mut with_bomb = state.clone();
+    with_bomb.bombs[0].1[0] = BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1);
+    let with_bomb = Game::drop_stone(with_bomb, ALICE, Side::North, 5).unwrap();
+    assert!(
+        !with_bomb.is_draw,
+        "A bomb left to detonate should prevent a draw"
+    );
+
+    state = Game::drop_stone(state, ALICE, Side::North, 5).unwrap();
+    assert!(state.is_stalemate());
+    assert!(state.is_draw);
+    assert_eq!(state.winner, None);
+    assert_eq!(
+        Game::drop_stone(state.clone(), BOB, Side::North, 5),
+        Err(GameError::GameAlreadyFinished)
+    );
+    assert!(Game::valid_stone_moves(&state, &BOB).is_empty());
+
+    let state = Game::soft_reset(state);
+    assert!(!state.is_draw);
+}
+
+#[test]
+fn should_end_in_a_draw_when_reaching_max_moves_during_bomb_phase() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.max_moves = Some(2);
+
+    state = Game::drop_bomb(state, Coordinates::new(5, 5), ALICE, SECRET_1).unwrap();
+    assert!(!state.is_draw);
+    state = Game::drop_bomb(state, Coordinates::new(5, 5), BOB, SECRET_2).unwrap();
+    assert_eq!(state.phase, GamePhase::Bomb);
+    assert!(state.is_draw);
+    assert_eq!(
+        Game::drop_bomb(state, Coordinates::new(5, 6), ALICE, SECRET_1),
+        Err(GameError::GameAlreadyFinished)
+    );
+}
+
+#[test]
+fn should_pass_the_turn_of_stuck_players() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.phase = GamePhase::Play;
+    // A checkerboard has no squares.
+    for row in 0..BOARD_HEIGHT {
+        for col in 0..BOARD_WIDTH {
+            state
+                .board
+                .update_cell(Coordinates::new(row, col), Cell::Stone((row + col) % 2));
+        }
+    }
+    state.board.update_cell(Coordinates::new(0, 5), Cell::Empty);
+    state.bombs[0].1[0] = BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1);
+    assert!(state.has_any_legal_move_for(&ALICE));
+    assert!(
+        state.has_any_legal_move_for(&BOB),
+        "Bob can still drop a stone"
+    );
+    assert!(!state.has_any_legal_move_for(&CHARLIE));
+
+    let skipped = Game::drop_stone(state.clone(), ALICE, Side::North, 5).unwrap();
+    assert!(!skipped.has_any_legal_move_for(&BOB));
+    assert!(skipped.has_any_legal_move_for(&ALICE));
+    assert!(!skipped.is_draw);
+    assert_eq!(skipped.next_player, ALICE, "Bob's turn should be skipped");
+    assert_eq!(skipped.winner, None);
+
+    state.config.stuck_player_rule = StuckPlayerRule::Forfeit;
+    let forfeited = Game::drop_stone(state, ALICE, Side::North, 5).unwrap();
+    assert!(forfeited.has_forfeited(&BOB));
+    assert_eq!(forfeited.winner, Some(ALICE));
+}
+
+#[test]
+fn should_end_in_a_draw_when_reaching_max_moves() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state = Game::drop_bomb(state, Coordinates::new(5, 5), ALICE, SECRET_1).unwrap();
+    state.phase = GamePhase::Play;
+    state.config.max_moves = Some(4);
+
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    state = Game::drop_stone(state, BOB, Side::North, 9).unwrap();
+    assert!(!state.is_draw);
+    assert_eq!(state.move_index, 3);
+
+    state = Game::detonate_bomb(state, ALICE, Coordinates::new(5, 5), SECRET_1).unwrap();
+    assert_eq!(state.move_index, 4);
+    assert!(state.is_draw);
+    assert_eq!(state.outcome(), GameOutcome::Draw);
+    assert_eq!(
+        Game::drop_stone(state.clone(), BOB, Side::North, 1),
+        Err(GameError::GameAlreadyFinished)
+    );
+
+    let mut winning =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    winning.config.max_moves = Some(1);
+    winning.phase = GamePhase::Play;
+    let o = Cell::Empty;
+    let a = Cell::Stone(0);
+    winning.board = board_from_cells([
+        [o, o, o, o, o, o, o, o, o, o],
+        [a, a, o, o, o, o, o, o, o, o],
+        [a, a, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, a],
+        [o, o, o, o, o, o, o, o, a, a],
+    ]);
+    let winning = Game::drop_stone(winning, ALICE, Side::North, 8).unwrap();
+    assert_eq!(
+        winning.winner,
+        Some(ALICE),
+        "A winning last move should still win"
+    );
+    assert!(!winning.is_draw);
+}
+
+#[test]
+fn should_emit_events_for_each_action() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.min_bombs_to_start = 2;
+
+    let (state, events) =
+        Game::drop_bomb_with_events(state, Coordinates::new(5, 5), ALICE, SECRET_1).unwrap();
+    assert!(events.is_empty());
+    let (mut state, events) =
+        Game::drop_bomb_with_events(state, Coordinates::new(2, 2), BOB, SECRET_2).unwrap();
+    assert_eq!(events, vec![GameEvent::PhaseChanged(GamePhase::Play)]);
+
+    state
+        .board
+        .update_cell(Coordinates::new(4, 4), Cell::Stone(1));
+    state.board.update_cell(Coordinates::new(4, 5), Cell::Block);
+    let (state, events) = Game::drop_stone_with_events(state, ALICE, Side::North, 6).unwrap();
+    assert_eq!(
+        events,
+        vec![GameEvent::StonePlaced {
+            player: ALICE,
+            coords: Coordinates::new(9, 6),
+        }]
+    );
+
+    let (state, events) =
+        Game::detonate_bomb_with_events(state, BOB, Coordinates::new(2, 2), SECRET_2).unwrap();
+    assert_eq!(
+        events,
+        vec![GameEvent::BombExploded {
+            at: Coordinates::new(2, 2),
+            cleared: Vec::new(),
+        }],
+        "Nothing to clear around an empty cell"
+    );
+
+    let (mut state, events) =
+        Game::detonate_bomb_with_events(state, ALICE, Coordinates::new(5, 5), SECRET_1).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::BombExploded {
+                at: Coordinates::new(5, 5),
+                cleared: vec![Coordinates::new(4, 4)],
+            },
+            GameEvent::StoneDestroyed {
+                owner: BOB,
+                coords: Coordinates::new(4, 4),
+            },
+        ]
+    );
+
+    for (row, col) in [
+        (0, 0),
+        (0, 1),
+        (1, 0),
+        (1, 1),
+        (0, 3),
+        (0, 4),
+        (1, 3),
+        (1, 4),
+    ] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(1));
+    }
+    for (row, col) in [(8, 7), (9, 7), (9, 8)] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(1));
+    }
+    let (_, events) = Game::drop_stone_with_events(state, BOB, Side::North, 8).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::StonePlaced {
+                player: BOB,
+                coords: Coordinates::new(8, 8),
+            },
+            GameEvent::Winner(BOB),
+        ]
+    );
+}
+
+#[test]
+fn should_count_moves() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.min_bombs_to_start = 2;
+    assert_eq!(state.move_index, 0);
+
+    state = Game::drop_bomb(state, Coordinates::new(5, 5), ALICE, SECRET_1).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(2, 2), BOB, SECRET_2).unwrap();
+    assert_eq!(state.move_index, 2);
+    assert!(Game::drop_bomb(state.clone(), Coordinates::new(3, 3), BOB, SECRET_2).is_err());
+
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    assert_eq!(state.move_index, 3);
+    assert!(Game::drop_stone(state.clone(), ALICE, Side::North, 1).is_err());
+    state = Game::detonate_bomb(state, BOB, Coordinates::new(2, 2), SECRET_2).unwrap();
+    assert_eq!(state.move_index, 4);
+
+    // Filling the third square of Alice wins the game.
+    for (row, col) in [
+        (0, 1),
+        (0, 2),
+        (1, 1),
+        (1, 2),
+        (0, 4),
+        (0, 5),
+        (1, 4),
+        (1, 5),
+    ] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+    for (row, col) in [(8, 7), (9, 7), (9, 8)] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+    state = Game::drop_stone(state, ALICE, Side::North, 8).unwrap();
+    assert_eq!(state.winner, Some(ALICE));
+    assert_eq!(state.move_index, 5);
+}
+
+#[test]
+fn should_report_game_outcome() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    assert_eq!(state.outcome(), GameOutcome::InProgress);
+
+    state.is_draw = true;
+    assert_eq!(state.outcome(), GameOutcome::Draw);
+
+    state.is_draw = false;
+    state.winner = Some(BOB);
+    assert_eq!(state.outcome(), GameOutcome::Win(BOB));
+}
+
+#[test]
+fn should_forfeit_a_game() {
+    let state = Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    assert_eq!(state.phase, GamePhase::Bomb);
+    let forfeited = Game::forfeit(state.clone(), ALICE).unwrap();
+    assert_eq!(forfeited.winner, Some(BOB));
+    assert_eq!(
+        Game::drop_bomb(forfeited.clone(), TEST_COORDINATES, BOB, SECRET_2),
+        Err(GameError::GameAlreadyFinished)
+    );
+    assert_eq!(
+        Game::forfeit(forfeited, BOB),
+        Err(GameError::GameAlreadyFinished)
+    );
+
+    let mut state = state;
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    assert_eq!(
+        Game::forfeit(state.clone(), CHARLIE),
+        Err(GameError::NotInGame)
+    );
+    let forfeited = Game::forfeit(state, ALICE).unwrap();
+    assert_eq!(forfeited.winner, Some(BOB));
+    assert_eq!(
+        Game::drop_stone(forfeited, BOB, Side::North, 1),
+        Err(GameError::GameAlreadyFinished)
+    );
+}
+
+#[test]
+fn should_drop_forfeiting_players_from_multiplayer_games() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB, CHARLIE],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        None,
+    )
+    .unwrap();
+    state.board = Board::new();
+
+    state = Game::forfeit(state, BOB).unwrap();
+    assert_eq!(state.winner, None, "Two players are still in the game");
+    assert_eq!(state.forfeited, vec![BOB]);
+    assert_eq!(
+        Game::drop_bomb(state.clone(), TEST_COORDINATES, BOB, SECRET_2),
+        Err(GameError::NotInGame)
+    );
+    assert_eq!(Game::forfeit(state.clone(), BOB), Err(GameError::NotInGame));
+
+    state.phase = GamePhase::Play;
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    assert_eq!(state.next_player, CHARLIE, "Bob's turn should be skipped");
+    state = Game::drop_stone(state, CHARLIE, Side::North, 1).unwrap();
+    assert_eq!(state.next_player, ALICE);
+
+    let state = Game::forfeit(state, ALICE).unwrap();
+    assert_eq!(state.next_player, CHARLIE);
+    assert_eq!(state.winner, Some(CHARLIE));
+    assert_eq!(
+        Game::soft_reset(state).forfeited,
+        Vec::<u8>::new(),
+        "Everyone should be back after a reset"
+    );
+}
+
+#[test]
+fn should_not_wait_for_bombs_of_forfeiting_players() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB, CHARLIE],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        None,
+    )
+    .unwrap();
+    state.board = Board::new();
+    for (player, row) in [(ALICE, 4), (CHARLIE, 5)] {
+        for col in 4..4 + NUM_OF_BOMBS_PER_PLAYER as u8 {
+            state = Game::drop_bomb(state, Coordinates::new(row, col), player, SECRET_1).unwrap();
+        }
+    }
+    assert_eq!(
+        state.phase,
+        GamePhase::Bomb,
+        "Bob hasn't dropped any bomb yet"
+    );
+
+    let state = Game::forfeit(state, BOB).unwrap();
+    assert_eq!(state.phase, GamePhase::Play);
+    assert_eq!(
+        state.bomb_phase_progress(),
+        BombPhaseProgress {
+            remaining: vec![(ALICE, 0), (CHARLIE, 0)],
+            ready_to_advance: true,
+        }
+    );
+}
+
+#[test]
+fn should_undo_the_last_stone_drop() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state = Game::drop_bomb(state, Coordinates::new(5, 5), ALICE, SECRET_1).unwrap();
+    state.phase = GamePhase::Play;
+    assert_eq!(Game::undo(state.clone()), Err(GameError::UndoNotSupported));
+
+    let before = state.clone();
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    assert_eq!(Game::undo(state.clone()), Ok(before));
+
+    let after_first_move = Game::drop_stone(state.clone(), BOB, Side::West, 0).unwrap();
+    let undone = Game::undo(after_first_move).unwrap();
+    assert_eq!(undone.board, state.board);
+    assert_eq!(undone.next_player, BOB);
+    assert_eq!(undone.last_move, state.last_move);
+    assert_eq!(
+        Game::undo(undone),
+        Err(GameError::UndoNotSupported),
+        "Only one move can be taken back"
+    );
+
+    state = Game::drop_stone(state, BOB, Side::West, 0).unwrap();
+    state = Game::detonate_bomb(state, ALICE, Coordinates::new(5, 5), SECRET_1).unwrap();
+    assert_eq!(Game::undo(state), Err(GameError::UndoNotSupported));
+}
+
+#[test]
+fn should_undo_a_winning_move() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+    for (row, col) in [
+        (0, 0),
+        (0, 1),
+        (1, 0),
+        (1, 1),
+        (0, 3),
+        (0, 4),
+        (1, 3),
+        (1, 4),
+    ] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+    for (row, col) in [(8, 6), (9, 6), (9, 7)] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+
+    let before = state.clone();
+    state = Game::drop_stone(state, ALICE, Side::North, 7).unwrap();
+    assert_eq!(state.winner, Some(ALICE));
+    assert_eq!(Game::undo(state), Ok(before));
+}
+
+#[test]
+fn should_report_next_player_index() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB, CHARLIE],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        None,
+    )
+    .unwrap();
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+    for (position, player) in [ALICE, BOB, CHARLIE, ALICE].into_iter().enumerate() {
+        assert_eq!(state.next_player_index(), state.player_index(&player));
+        assert_eq!(
+            state.next_player_index(),
+            state.player_index(&state.next_player)
+        );
+        state = Game::drop_stone(state, player, Side::North, position as u8).unwrap();
+    }
+    assert_eq!(state.next_player_index(), 1);
+}
+
+#[test]
+fn should_play_a_four_player_game() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB, CHARLIE, DAVE],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        None,
+    )
+    .unwrap();
+    state.board = Board::new();
+    assert_eq!(state.players, vec![ALICE, BOB, CHARLIE, DAVE]);
+    assert_eq!(state.bombs.len(), 4);
+
+    for (row, player) in [ALICE, BOB, CHARLIE, DAVE].into_iter().enumerate() {
+        for col in 0..NUM_OF_BOMBS_PER_PLAYER as u8 {
+            assert_eq!(state.phase, GamePhase::Bomb);
+            state =
+                Game::drop_bomb(state, Coordinates::new(row as u8, col), player, SECRET_1).unwrap();
+        }
+    }
+    assert_eq!(state.phase, GamePhase::Play);
+
+    // Turns rotate through all players.
+    for player in [ALICE, BOB, CHARLIE, DAVE, ALICE] {
+        assert_eq!(state.next_player, player);
+        state = Game::drop_stone(state, player, Side::North, 0).unwrap();
+    }
+    assert_eq!(
+        Game::drop_stone(state.clone(), CHARLIE, Side::North, 1),
+        Err(GameError::NotPlayerTurn)
+    );
+
+    // Give Charlie two squares, the next one wins.
+    state.board = Board::new();
+    for (row, col) in [
+        (0, 0),
+        (0, 1),
+        (1, 0),
+        (1, 1),
+        (0, 3),
+        (0, 4),
+        (1, 3),
+        (1, 4),
+    ] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(2));
+    }
+    for (row, col) in [(8, 6), (9, 6), (9, 7)] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(2));
+    }
+    state.next_player = CHARLIE;
+    state = Game::drop_stone(state, CHARLIE, Side::North, 7).unwrap();
+    assert_eq!(state.winner, Some(CHARLIE));
+    assert_eq!(state.bomb_phase_progress().remaining.len(), 4);
+}
+
+#[test]
+fn should_create_new_game_with_custom_board_size() {
+    for (width, height) in [(6, 6), (15, 15), (7, 12)] {
+        let board_config = BoardConfig {
+            width,
+            height,
+            num_blocks: 5,
+        };
+        let mut state = Game::new_game(
+            vec![ALICE, BOB],
+            Some(INITIAL_SEED),
+            GENESIS_BLOCK,
+            Some(board_config),
+        )
+        .unwrap();
+        assert_eq!(state.board.width(), width);
+        assert_eq!(state.board.height(), height);
+        let flat = state.board.to_flat();
+        assert_eq!(flat.len(), width as usize * height as usize);
+        assert_eq!(flat.iter().filter(|cell| **cell == 1).count(), 5);
+
+        // Stones slide up to the opposite edge of the custom board.
+        state.board = Board::with_size(width, height);
+        state.phase = GamePhase::Play;
+        state = Game::drop_stone(state, ALICE, Side::North, width - 1).unwrap();
+        assert_eq!(
+            state.last_move.unwrap().landing,
+            Coordinates::new(height - 1, width - 1)
+        );
+        state = Game::drop_stone(state, BOB, Side::West, 0).unwrap();
+        assert_eq!(
+            state.last_move.unwrap().landing,
+            Coordinates::new(0, width - 1)
+        );
+        assert_eq!(
+            Game::drop_stone(state.clone(), ALICE, Side::North, width),
+            Err(GameError::InvalidStonePosition)
+        );
+
+        let encoded = state.encode_minimal();
+        assert_eq!(
+            DisplayState::decode_minimal(&encoded).map(|display| display.board),
+            Some(state.board)
+        );
+    }
+}
+
+#[test]
+fn should_win_on_custom_board_size() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        Some(BoardConfig {
+            width: 15,
+            height: 15,
+            num_blocks: 0,
+        }),
+    )
+    .unwrap();
+    state.phase = GamePhase::Play;
+    for (row, col) in [
+        (0, 5),
+        (0, 6),
+        (1, 5),
+        (1, 6),
+        (13, 0),
+        (13, 1),
+        (14, 0),
+        (14, 1),
+        (13, 13),
+        (14, 13),
+        (14, 14),
+    ] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+    state = Game::drop_stone(state, ALICE, Side::North, 14).unwrap();
+    assert_eq!(state.winner, Some(ALICE));
+}
+
+#[test]
+fn should_not_create_new_game_with_invalid_board() {
+    let new_game = |width, height, num_blocks| {
+        Game::new_game(
+            vec![ALICE, BOB],
+            Some(INITIAL_SEED),
+            GENESIS_BLOCK,
+            Some(BoardConfig {
+                width,
+                height,
+                num_blocks,
+            }),
+        )
+    };
+    assert_eq!(new_game(1, 10, 0), Err(GameError::InvalidBoardSize));
+    assert_eq!(new_game(10, 16, 0), Err(GameError::InvalidBoardSize));
+    assert_eq!(new_game(3, 3, 10), Err(GameError::TooManyBlocks));
+    assert_eq!(new_game(3, 3, 9), Err(GameError::TooManyBlocks));
+    // Blocks must leave room for each player's bombs.
+    assert_eq!(new_game(3, 3, 7), Err(GameError::TooManyBlocks));
+    assert!(new_game(3, 3, 6).is_ok());
+    assert!(new_game(3, 3, 1).is_ok());
+}
+
+#[test]
+fn should_place_blocks_in_the_last_row_and_column() {
+    let (mut in_last_row, mut in_last_col) = (false, false);
+    for seed in 0..100 {
+        let state = Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
+        for row in 0..BOARD_HEIGHT {
+            for col in 0..BOARD_WIDTH {
+                if state.board.get_cell(&Coordinates::new(row, col)) == Cell::Block {
+                    in_last_row |= row == BOARD_HEIGHT - 1;
+                    in_last_col |= col == BOARD_WIDTH - 1;
+                }
+            }
+        }
+    }
+    assert!(in_last_row, "Blocks should be placed in the last row");
+    assert!(in_last_col, "Blocks should be placed in the last column");
+}
+
+#[test]
+fn should_spread_blocks_uniformly_across_the_board() {
+    let mut blocks_per_cell = [[0_u32; BOARD_WIDTH as usize]; BOARD_HEIGHT as usize];
+    for seed in 0..1_000 {
+        let state = Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
+        for row in 0..BOARD_HEIGHT {
+            for col in 0..BOARD_WIDTH {
+                if state.board.get_cell(&Coordinates::new(row, col)) == Cell::Block {
+                    blocks_per_cell[row as usize][col as usize] += 1;
+                }
+            }
+        }
+    }
+
+    // Each cell is expected to hold a block in 100 of the 1000 games.
+    for (row, cells) in blocks_per_cell.iter().enumerate() {
+        for (col, blocks) in cells.iter().enumerate() {
+            assert!(
+                (60..=140).contains(blocks),
+                "Cell ({row},{col}) got a block {blocks} times"
+            );
+        }
+    }
+}
+
+#[test]
+fn should_create_new_game_with_random_blocks() {
+    let blocks = |board: Board| -> u8 {
+        let mut block_count = 0;
+        board.cells.iter().for_each(|row| {
+            row.iter().for_each(|cell| {
+                if let Cell::Block = cell {
+                    block_count += 1;
+                }
+            })
+        });
+        block_count
+    };
+
+    let (mut seed_1, mut seed_2) = (123, 456);
+    for _ in 0..20 {
+        let game_1 = Game::new_game(vec![ALICE, BOB], Some(seed_1), GENESIS_BLOCK, None).unwrap();
+        let game_2 = Game::new_game(vec![ALICE, BOB], Some(seed_2), GENESIS_BLOCK, None).unwrap();
+        assert_ne!(game_1.board, game_2.board);
+        assert_eq!(blocks(game_1.board), NUM_OF_BLOCKS);
+        assert_eq!(blocks(game_2.board), NUM_OF_BLOCKS);
+        assert_ne!(seed_1, game_1.seed, "seed 1 should be updated");
+        assert_ne!(seed_2, game_2.seed, "seed 2 should be updated");
+        seed_1 = game_1.seed;
+        seed_2 = game_2.seed;
+    }
+}
+
+#[test]
+fn should_create_new_game_with_deterministic_blocks_with_fixed_seed() {
+    let seed = 7357;
+    for _ in 0..20 {
+        let game_1 = Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
+        let game_2 = Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
+        assert_eq!(game_1.board, game_2.board);
+    }
+}
+
+#[test]
+fn should_keep_initial_seed_during_game() {
+    let seed = 7357;
+    let mut state = Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
+    assert_eq!(state.initial_seed, seed);
+    assert_ne!(
+        state.seed, seed,
+        "The seed should evolve during block placement"
+    );
+
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    state = Game::drop_stone(state, BOB, Side::North, 1).unwrap();
+    assert_eq!(state.initial_seed, seed);
+}
+
+#[test]
+fn should_compute_elapsed_blocks() {
+    let state = Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    assert_eq!(state.genesis_block, GENESIS_BLOCK);
+    assert_eq!(state.elapsed_blocks(GENESIS_BLOCK), 0);
+    assert_eq!(state.elapsed_blocks(GENESIS_BLOCK + 42), 42);
+    assert_eq!(state.elapsed_blocks(GENESIS_BLOCK - 1), 0);
+}
+
+#[test]
+fn should_resolve_draw_deterministically_from_seed() {
+    for seed in 0..10 {
+        let state = Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None).unwrap();
+        let player = state.resolve_draw_with_seed();
+        for _ in 0..5 {
+            assert_eq!(state.resolve_draw_with_seed(), player);
+        }
+        assert_eq!(
+            Game::new_game(vec![ALICE, BOB], Some(seed), GENESIS_BLOCK, None)
+                .unwrap()
+                .resolve_draw_with_seed(),
+            player
+        );
+    }
+}
+
+#[test]
+fn should_resolve_draws_evenly_across_seeds() {
+    for players in [vec![ALICE, BOB], vec![ALICE, BOB, CHARLIE, DAVE]] {
+        for seeds in [
+            (0..2_000).collect::<Vec<_>>(),
+            (0..2_000).map(|seed| seed * 2).collect(),
+        ] {
+            let mut picks = players.iter().map(|_| 0).collect::<Vec<_>>();
+            for seed in seeds {
+                let state =
+                    Game::new_game(players.clone(), Some(seed), GENESIS_BLOCK, None).unwrap();
+                let pick = state.resolve_draw_with_seed();
+                picks[players.iter().position(|player| *player == pick).unwrap()] += 1;
+            }
+
+            let expected = 2_000 / players.len();
+            for picked in picks {
+                assert!(
+                    picked > expected * 9 / 10 && picked < expected * 11 / 10,
+                    "Picked {picked} times, expected around {expected}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn should_compare_states_ignoring_seed() {
+    let state = Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let mut other = state.clone();
+    other.seed += 1;
+    assert_ne!(state, other);
+    assert!(state.eq_ignoring_seed(&other));
+    assert!(other.eq_ignoring_seed(&state));
+
+    other.initial_seed += 1;
+    assert!(!state.eq_ignoring_seed(&other));
+
+    let mut other = state.clone();
+    other.next_player = BOB;
+    assert!(!state.eq_ignoring_seed(&other));
+}
+
+#[test]
+fn should_reseed_only_before_any_move() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    assert_eq!(state.current_seed(), state.seed);
+
+    assert_eq!(state.reseed(7357), Ok(()));
+    assert_eq!(state.current_seed(), 7357);
+    assert_eq!(state.initial_seed, INITIAL_SEED);
+
+    state = Game::drop_bomb(state, TEST_COORDINATES, ALICE, SECRET_1).unwrap();
+    assert_eq!(state.reseed(42), Err(GameError::GameAlreadyStarted));
+    assert_eq!(state.current_seed(), 7357);
+
+    let mut state = Game::soft_reset(state);
+    state.phase = GamePhase::Play;
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    assert_eq!(state.reseed(42), Err(GameError::GameAlreadyStarted));
+}
+
+#[test]
+fn should_create_new_game_with_canonical_player_order() {
+    let game_1 = Game::new_game_with_canonical_order(
+        vec![ALICE, BOB],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        None,
+    )
+    .unwrap();
+    let game_2 = Game::new_game_with_canonical_order(
+        vec![BOB, ALICE],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(game_1.player_index(&ALICE), game_2.player_index(&ALICE));
+    assert_eq!(game_1.player_index(&BOB), game_2.player_index(&BOB));
+    assert_eq!(game_1.players, game_2.players);
+    assert_eq!(game_1.bombs, game_2.bombs);
+    assert_eq!(game_1.next_player, ALICE, "The first player should start");
+    assert_eq!(game_2.next_player, BOB, "The first player should start");
+}
+
+#[test]
+fn a_player_cannot_drop_bomb_in_play_phase() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.phase = GamePhase::Play;
+    let result = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1);
+    assert_eq!(result, Err(GameError::DroppedBombOutsideBombPhase));
+}
+
+#[test]
+fn a_player_cannot_drop_bomb_if_already_dropped_all() {
+    for _ in 0..NUM_OF_BOMBS_PER_PLAYER {
+        let mut game_state =
+            Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+        game_state.bombs = PlayerBombs::truncate_from(vec![
+            (ALICE, [BombState::Detonated; NUM_OF_BOMBS_PER_PLAYER]),
+            (BOB, [BombState::Detonated; NUM_OF_BOMBS_PER_PLAYER]),
+        ]);
+        assert_eq!(
+            Game::drop_bomb(game_state.clone(), TEST_COORDINATES, ALICE, SECRET_1),
+            Err(GameError::NoMoreBombsAvailable)
+        );
+
+        assert_eq!(
+            Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2),
+            Err(GameError::NoMoreBombsAvailable)
+        );
+    }
+}
+
+#[test]
+fn a_player_cannot_drop_bomb_if_game_already_finished() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.winner = Some(ALICE);
+    assert_eq!(
+        Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_1),
+        Err(GameError::GameAlreadyFinished),
+    )
+}
+
+#[test]
+fn dropping_bomb_should_not_update_last_move() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
+
+    assert!(Game::drop_bomb(game_state.clone(), TEST_COORDINATES, ALICE, SECRET_2).is_ok());
+    assert_eq!(game_state.last_move, None);
+}
+
+#[test]
+fn a_player_drops_a_bomb() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
+
+    let player_bombs = game_state.get_player_bombs(&ALICE).unwrap();
+    assert_eq!(player_bombs, NUM_OF_BOMBS_PER_PLAYER as u8);
+
+    let game_state = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1).unwrap();
+    assert_eq!(
+        game_state.get_player_bombs(&ALICE).unwrap(),
+        player_bombs - 1,
+        "The player should have one bomb less available for dropping"
+    );
+    assert_eq!(
+        game_state.bombs[0],
+        (
+            ALICE,
+            [
+                BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1),
+                BombState::NotPlaced,
+                BombState::NotPlaced
+            ]
+        )
+    )
+}
+
+#[test]
+fn a_cell_can_hold_one_or_more_bombs_from_different_players() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
+
+    let drop_bomb_result = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1);
+    assert!(drop_bomb_result.is_ok());
+    game_state = drop_bomb_result.unwrap();
+
+    assert_eq!(
         game_state.bombs,
-        [
+        vec![
             (
                 ALICE,
                 [
@@ -237,73 +1730,361 @@ fn a_cell_can_hold_one_or_more_bombs_from_different_players() {
         ]
     );
 
-    let drop_bomb_result = Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2);
-    assert!(drop_bomb_result.is_ok());
-    game_state = drop_bomb_result.unwrap();
+    let drop_bomb_result = Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2);
+    assert!(drop_bomb_result.is_ok());
+    game_state = drop_bomb_result.unwrap();
+
+    assert_eq!(
+        game_state.bombs,
+        vec![
+            (
+                ALICE,
+                [
+                    BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1),
+                    BombState::NotPlaced,
+                    BombState::NotPlaced
+                ]
+            ),
+            (
+                BOB,
+                [
+                    BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_2), SECRET_2),
+                    BombState::NotPlaced,
+                    BombState::NotPlaced
+                ]
+            )
+        ]
+    );
+}
+
+#[test]
+fn a_cell_cannot_hold_more_than_allowed_number_of_bombs() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let (alice_index, bob_index) = (
+        game_state.player_index(&ALICE),
+        game_state.player_index(&BOB),
+    );
+
+    game_state.bombs[alice_index as usize].1[0] =
+        BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1);
+    game_state.bombs[bob_index as usize].1[0] =
+        BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_2), SECRET_2);
+
+    assert_eq!(
+        Game::drop_bomb(game_state.clone(), TEST_COORDINATES, ALICE, SECRET_1),
+        Err(GameError::InvalidBombPosition)
+    );
+    assert_eq!(
+        Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2),
+        Err(GameError::InvalidBombPosition)
+    );
+}
+
+#[test]
+fn a_bomb_cannot_be_placed_in_a_cell_occupied_by_a_block() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board.update_cell(TEST_COORDINATES, Cell::Block);
+    assert_eq!(
+        Game::drop_bomb(game_state.clone(), TEST_COORDINATES, ALICE, SECRET_1),
+        Err(GameError::InvalidBombPosition)
+    );
+    assert_eq!(
+        Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2),
+        Err(GameError::InvalidBombPosition)
+    );
+}
+
+#[test]
+fn detonating_a_bomb_should_keep_other_players_bomb_in_the_same_cell() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board = Board::new();
+    let bomb_position = Coordinates::new(5, 5);
+
+    game_state = Game::drop_bomb(game_state, bomb_position, ALICE, SECRET_1).unwrap();
+    game_state = Game::drop_bomb(game_state, bomb_position, BOB, SECRET_2).unwrap();
+    game_state.phase = GamePhase::Play;
+
+    game_state = Game::detonate_bomb(game_state, ALICE, bomb_position, SECRET_1).unwrap();
+    assert_eq!(game_state.bombs[0].1[0], BombState::Detonated);
+    assert_eq!(
+        game_state.bombs[1].1[0],
+        BombState::Placed(bomb_position.generate_hash(SECRET_2), SECRET_2),
+        "The other player's bomb should survive"
+    );
+    assert!(game_state.is_player_bomb_at(&BOB, &bomb_position));
+
+    game_state = Game::detonate_bomb(game_state, BOB, bomb_position, SECRET_2).unwrap();
+    assert_eq!(game_state.bombs[1].1[0], BombState::Detonated);
+    assert_eq!(
+        Game::detonate_bomb(game_state, ALICE, bomb_position, SECRET_1),
+        Err(GameError::InvalidBombPosition),
+        "A detonated bomb cannot be detonated again"
+    );
+}
+
+#[test]
+fn scorched_cells_should_block_drops_until_they_cool_down() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.scorch_duration = Some(2);
+    let bomb_position = Coordinates::new(5, 5);
+    state = Game::drop_bomb(state, bomb_position, ALICE, SECRET_1).unwrap();
+    state.phase = GamePhase::Play;
+    state
+        .board
+        .update_cell(Coordinates::new(6, 6), Cell::Stone(1));
+
+    let (mut state, events) =
+        Game::detonate_bomb_with_events(state, ALICE, bomb_position, SECRET_1).unwrap();
+    let scorched = Cell::Scorched { until_move: 4 };
+    for position in state.board.blast_cells(bomb_position) {
+        assert_eq!(state.board.get_cell(&position), scorched);
+        assert!(!state.board.is_bomb_droppable(&position));
+    }
+    let mut cleared = state.board.blast_cells(bomb_position);
+    cleared.sort_by_key(|coords| (coords.row, coords.col));
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::BombExploded {
+                at: bomb_position,
+                cleared,
+            },
+            GameEvent::StoneDestroyed {
+                owner: BOB,
+                coords: Coordinates::new(6, 6),
+            },
+        ]
+    );
+
+    state = Game::drop_stone(state, BOB, Side::North, 5).unwrap();
+    assert_eq!(
+        state.last_move.as_ref().map(|last_move| last_move.landing),
+        Some(Coordinates::new(3, 5)),
+        "Scorched cells should stop stones like blocks"
+    );
+    assert_eq!(state.board.get_cell(&bomb_position), scorched);
+
+    state = Game::drop_stone(state, ALICE, Side::North, 4).unwrap();
+    assert_eq!(state.move_index, 4);
+    for position in state.board.blast_cells(bomb_position) {
+        assert_eq!(state.board.get_cell(&position), Cell::Empty);
+    }
+    assert_eq!(
+        Game::undo(state.clone()),
+        Err(GameError::UndoNotSupported),
+        "Cooled down cells can't be brought back"
+    );
 
+    state = Game::drop_stone(state, BOB, Side::North, 6).unwrap();
     assert_eq!(
-        game_state.bombs,
-        [
-            (
-                ALICE,
-                [
-                    BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1),
-                    BombState::NotPlaced,
-                    BombState::NotPlaced
-                ]
-            ),
-            (
-                BOB,
-                [
-                    BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_2), SECRET_2),
-                    BombState::NotPlaced,
-                    BombState::NotPlaced
-                ]
-            )
-        ]
+        state.last_move.map(|last_move| last_move.landing),
+        Some(Coordinates::new(9, 6))
     );
 }
 
 #[test]
-fn a_cell_cannot_hold_more_than_allowed_number_of_bombs() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    let (alice_index, bob_index) = (
-        game_state.player_index(&ALICE),
-        game_state.player_index(&BOB),
+fn detonating_a_bomb_in_convert_mode_should_convert_opponent_stones() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.explosion_mode = ExplosionMode::Convert;
+    let bomb_position = Coordinates::new(5, 5);
+    state = Game::drop_bomb(state, bomb_position, ALICE, SECRET_1).unwrap();
+    state.phase = GamePhase::Play;
+
+    state
+        .board
+        .update_cell(Coordinates::new(4, 4), Cell::Stone(1));
+    state
+        .board
+        .update_cell(Coordinates::new(5, 5), Cell::Stone(1));
+    state
+        .board
+        .update_cell(Coordinates::new(6, 6), Cell::Stone(0));
+    state.board.update_cell(Coordinates::new(4, 6), Cell::Block);
+    state
+        .board
+        .update_cell(Coordinates::new(7, 7), Cell::Stone(1));
+
+    let (state, events) =
+        Game::detonate_bomb_with_events(state, ALICE, bomb_position, SECRET_1).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::BombExploded {
+                at: bomb_position,
+                cleared: vec![],
+            },
+            GameEvent::StoneConverted {
+                coords: Coordinates::new(4, 4),
+                from: BOB,
+                to: ALICE,
+            },
+            GameEvent::StoneConverted {
+                coords: Coordinates::new(5, 5),
+                from: BOB,
+                to: ALICE,
+            },
+        ]
+    );
+    assert_eq!(
+        state.board.get_cell(&Coordinates::new(4, 4)),
+        Cell::Stone(0)
+    );
+    assert_eq!(
+        state.board.get_cell(&Coordinates::new(5, 5)),
+        Cell::Stone(0)
+    );
+    assert_eq!(
+        state.board.get_cell(&Coordinates::new(6, 6)),
+        Cell::Stone(0)
+    );
+    assert_eq!(state.board.get_cell(&Coordinates::new(4, 6)), Cell::Block);
+    assert_eq!(state.board.get_cell(&Coordinates::new(5, 4)), Cell::Empty);
+    assert_eq!(
+        state.board.get_cell(&Coordinates::new(7, 7)),
+        Cell::Stone(1),
+        "Stones outside the blast should be kept"
     );
+    assert_eq!(state.next_player, BOB);
+}
 
-    game_state.bombs[alice_index as usize].1[0] =
-        BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_1), SECRET_1);
-    game_state.bombs[bob_index as usize].1[0] =
-        BombState::Placed(TEST_COORDINATES.generate_hash(SECRET_2), SECRET_2);
+#[test]
+fn converting_stones_can_win_the_game() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.explosion_mode = ExplosionMode::Convert;
+    let bomb_position = Coordinates::new(5, 5);
+    state = Game::drop_bomb(state, bomb_position, ALICE, SECRET_1).unwrap();
+    state.phase = GamePhase::Play;
+
+    for (row, col) in [
+        (0, 0),
+        (0, 1),
+        (1, 0),
+        (1, 1),
+        (0, 3),
+        (0, 4),
+        (1, 3),
+        (1, 4),
+    ] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+    for (row, col) in [(4, 4), (4, 5), (5, 4), (5, 5)] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(1));
+    }
+
+    state = Game::detonate_bomb(state, ALICE, bomb_position, SECRET_1).unwrap();
+    assert_eq!(state.winner, Some(ALICE));
+}
+
+#[test]
+fn a_bomb_cannot_be_placed_in_the_safe_zone() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board = Board::new();
+    game_state.config.bomb_edge_margin = 2;
+
+    for position in [
+        Coordinates::new(0, 0),
+        Coordinates::new(1, 5),
+        Coordinates::new(5, 8),
+        Coordinates::new(9, 4),
+        Coordinates::new(4, 1),
+    ] {
+        assert_eq!(
+            Game::drop_bomb(game_state.clone(), position, ALICE, SECRET_1),
+            Err(GameError::BombInSafeZone)
+        );
+    }
+    for position in [
+        Coordinates::new(2, 2),
+        Coordinates::new(2, 7),
+        Coordinates::new(7, 7),
+        Coordinates::new(5, 5),
+    ] {
+        assert!(Game::drop_bomb(game_state.clone(), position, ALICE, SECRET_1).is_ok());
+    }
+
+    game_state.config.bomb_edge_margin = 0;
+    assert!(Game::drop_bomb(game_state, Coordinates::new(0, 0), ALICE, SECRET_1).is_ok());
+}
+
+#[test]
+fn should_validate_bomb_batch() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.board = Board::new();
 
     assert_eq!(
-        Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1),
+        game_state.validate_bomb_batch(
+            &ALICE,
+            &[
+                Coordinates::new(1, 1),
+                Coordinates::new(2, 2),
+                Coordinates::new(1, 1)
+            ]
+        ),
         Err(GameError::InvalidBombPosition)
     );
     assert_eq!(
-        Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2),
+        game_state.validate_bomb_batch(&ALICE, &[Coordinates::new(1, 1), Coordinates::new(10, 2)]),
         Err(GameError::InvalidBombPosition)
     );
-}
+    assert_eq!(
+        game_state.validate_bomb_batch(
+            &ALICE,
+            &[
+                Coordinates::new(1, 1),
+                Coordinates::new(2, 2),
+                Coordinates::new(3, 3),
+                Coordinates::new(4, 4)
+            ]
+        ),
+        Err(GameError::NoMoreBombsAvailable)
+    );
+    assert_eq!(
+        game_state.validate_bomb_batch(&CHARLIE, &[Coordinates::new(1, 1)]),
+        Err(GameError::NotInGame)
+    );
 
-#[test]
-fn a_bomb_cannot_be_placed_in_a_cell_occupied_by_a_block() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.board.update_cell(TEST_COORDINATES, Cell::Block);
+    let game_state = Game::drop_bomb(game_state, Coordinates::new(1, 1), ALICE, SECRET_1).unwrap();
     assert_eq!(
-        Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1),
+        game_state.validate_bomb_batch(&ALICE, &[Coordinates::new(1, 1)]),
         Err(GameError::InvalidBombPosition)
     );
     assert_eq!(
-        Game::drop_bomb(game_state, TEST_COORDINATES, BOB, SECRET_2),
-        Err(GameError::InvalidBombPosition)
+        game_state.validate_bomb_batch(
+            &ALICE,
+            &[
+                Coordinates::new(2, 2),
+                Coordinates::new(3, 3),
+                Coordinates::new(4, 4)
+            ]
+        ),
+        Err(GameError::NoMoreBombsAvailable)
+    );
+    assert_eq!(
+        game_state.validate_bomb_batch(&ALICE, &[Coordinates::new(2, 2), Coordinates::new(3, 3)]),
+        Ok(())
     );
+    assert_eq!(game_state.get_player_bombs(&ALICE), Some(2));
 }
 
 #[test]
 fn a_player_cannot_place_more_than_one_bomb_in_a_cell() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let alice_index = game_state.player_index(&ALICE);
     game_state.board.update_cell(TEST_COORDINATES, Cell::Empty);
 
@@ -327,88 +2108,542 @@ fn a_player_cannot_place_more_than_one_bomb_in_a_cell() {
         ),
     );
 
-    // Drop the second bomb
-    let drop_bomb_result = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1);
-    assert_eq!(drop_bomb_result, Err(GameError::InvalidBombPosition));
+    // Drop the second bomb
+    let drop_bomb_result = Game::drop_bomb(game_state, TEST_COORDINATES, ALICE, SECRET_1);
+    assert_eq!(drop_bomb_result, Err(GameError::InvalidBombPosition));
+}
+
+#[test]
+fn should_report_bomb_ownership_grid() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    assert!(state
+        .bomb_ownership_grid()
+        .iter()
+        .all(|row| row.iter().all(|owners| owners.is_empty())));
+
+    state = Game::drop_bomb(state, Coordinates::new(0, 0), ALICE, SECRET_1).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(0, 1), ALICE, SECRET_1).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(0, 1), BOB, SECRET_2).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(1, 1), BOB, SECRET_2).unwrap();
+
+    let grid = state.bomb_ownership_grid();
+    assert_eq!(grid.len(), BOARD_HEIGHT as usize);
+    assert!(grid.iter().all(|row| row.len() == BOARD_WIDTH as usize));
+    assert_eq!(grid[0][0], vec![0]);
+    assert_eq!(grid[0][1], vec![0, 1]);
+    assert_eq!(grid[1][1], vec![1]);
+    assert_eq!(
+        grid.iter()
+            .flatten()
+            .filter(|owners| !owners.is_empty())
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn should_report_bomb_phase_progress() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    assert_eq!(
+        state.bomb_phase_progress(),
+        BombPhaseProgress {
+            remaining: vec![
+                (ALICE, NUM_OF_BOMBS_PER_PLAYER as u8),
+                (BOB, NUM_OF_BOMBS_PER_PLAYER as u8)
+            ],
+            ready_to_advance: false,
+        }
+    );
+
+    for col in 0..NUM_OF_BOMBS_PER_PLAYER as u8 {
+        state = Game::drop_bomb(state, Coordinates::new(0, col), ALICE, SECRET_1).unwrap();
+    }
+    assert_eq!(
+        state.bomb_phase_progress(),
+        BombPhaseProgress {
+            remaining: vec![(ALICE, 0), (BOB, NUM_OF_BOMBS_PER_PLAYER as u8)],
+            ready_to_advance: false,
+        }
+    );
+
+    for col in 0..NUM_OF_BOMBS_PER_PLAYER as u8 - 1 {
+        state = Game::drop_bomb(state, Coordinates::new(1, col), BOB, SECRET_2).unwrap();
+    }
+    assert_eq!(
+        state.bomb_phase_progress(),
+        BombPhaseProgress {
+            remaining: vec![(ALICE, 0), (BOB, 1)],
+            ready_to_advance: false,
+        }
+    );
+
+    state = Game::drop_bomb(state, Coordinates::new(2, 0), BOB, SECRET_2).unwrap();
+    assert_eq!(
+        state.bomb_phase_progress(),
+        BombPhaseProgress {
+            remaining: vec![(ALICE, 0), (BOB, 0)],
+            ready_to_advance: true,
+        }
+    );
+    assert_eq!(state.phase, GamePhase::Play);
+}
+
+#[test]
+fn soft_reset_should_clear_stones_and_bombs_but_keep_blocks() {
+    let o = Cell::Empty;
+    let b = Cell::Block;
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let blocks = [
+        [o, o, o, o, o, o, o, o, b, o],
+        [b, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, b, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, b, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+    ];
+    state.board = board_from_cells(blocks);
+
+    for col in 0..NUM_OF_BOMBS_PER_PLAYER as u8 {
+        state = Game::drop_bomb(state, Coordinates::new(5, col), ALICE, SECRET_1).unwrap();
+        state = Game::drop_bomb(state, Coordinates::new(5, col), BOB, SECRET_2).unwrap();
+    }
+    state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
+    state = Game::drop_stone(state, BOB, Side::West, 2).unwrap();
+    state = Game::detonate_bomb(state, ALICE, Coordinates::new(5, 0), SECRET_1).unwrap();
+    state.winner = Some(BOB);
+    assert_eq!(state.move_index, 9);
+
+    let state = Game::soft_reset(state);
+    assert_eq!(state.board, board_from_cells(blocks));
+    assert_eq!(state.move_index, 0);
+    assert_eq!(state.phase, GamePhase::Bomb);
+    assert_eq!(state.winner, None);
+    assert_eq!(state.next_player, ALICE);
+    assert_eq!(state.last_move, None);
+    assert_eq!(
+        state.get_player_bombs(&ALICE),
+        Some(NUM_OF_BOMBS_PER_PLAYER as u8)
+    );
+    assert_eq!(
+        state.get_player_bombs(&BOB),
+        Some(NUM_OF_BOMBS_PER_PLAYER as u8)
+    );
+    assert!(!state.is_player_bomb_at(&BOB, &Coordinates::new(5, 1)));
+}
+
+#[test]
+fn play_phase_should_start_once_the_minimum_bombs_are_dropped() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.config.min_bombs_to_start = 3;
+
+    state = Game::drop_bomb(state, Coordinates::new(1, 1), ALICE, SECRET_1).unwrap();
+    state = Game::drop_bomb(state, Coordinates::new(2, 2), BOB, SECRET_2).unwrap();
+    assert_eq!(state.phase, GamePhase::Bomb);
+    assert!(!state.bomb_phase_progress().ready_to_advance);
+
+    state = Game::drop_bomb(state, Coordinates::new(3, 3), ALICE, SECRET_1).unwrap();
+    assert_eq!(state.phase, GamePhase::Play);
+    assert!(state.bomb_phase_progress().ready_to_advance);
+    assert_eq!(state.get_player_bombs(&ALICE), Some(1));
+    assert_eq!(state.get_player_bombs(&BOB), Some(2));
+    assert_eq!(
+        Game::drop_bomb(state, Coordinates::new(4, 4), BOB, SECRET_2),
+        Err(GameError::DroppedBombOutsideBombPhase),
+        "Bombs not dropped in time should be forfeited"
+    );
+}
+
+#[test]
+fn a_game_can_change_game_phase() {
+    let game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    assert_eq!(game_state.phase, GamePhase::Bomb);
+    let game_state = Game::change_game_phase(game_state, GamePhase::Play);
+    assert_eq!(game_state.phase, GamePhase::Play);
+    let game_state = Game::change_game_phase(game_state, GamePhase::Bomb);
+    assert_eq!(game_state.phase, GamePhase::Bomb);
+}
+
+#[test]
+fn a_player_cannot_drop_a_stone_in_bomb_phase() {
+    let state = Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    assert_eq!(state.phase, GamePhase::Bomb);
+    assert_eq!(
+        Game::drop_stone(state, BOB, Side::North, 0),
+        Err(GameError::DroppedStoneOutsidePlayPhase)
+    );
+}
+
+#[test]
+fn a_player_cannot_drop_a_stone_out_of_turn() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.phase = GamePhase::Play;
+    let drop_stone_result = Game::drop_stone(state, BOB, Side::North, 0);
+    assert_eq!(drop_stone_result, Err(GameError::NotPlayerTurn));
+}
+
+#[test]
+fn a_player_cannot_drop_stone_if_game_already_finished() {
+    let mut game_state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    game_state.phase = GamePhase::Play;
+    game_state.winner = Some(BOB);
+    assert_eq!(
+        Game::drop_stone(game_state, ALICE, Side::East, 1),
+        Err(GameError::GameAlreadyFinished),
+    )
+}
+
+#[test]
+fn player_turn_changes_after_dropping_stone() {
+    let mut state =
+        Game::new_game(vec![CHARLIE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    for i in 0..BOARD_WIDTH {
+        state.board.update_cell(Coordinates::new(i, 0), Cell::Empty);
+    }
+    state.phase = GamePhase::Play;
+    let drop_stone_result = Game::drop_stone(state, CHARLIE, Side::North, 0);
+    assert!(drop_stone_result.is_ok());
+    let state = drop_stone_result.unwrap();
+
+    let drop_stone_result = Game::drop_stone(state.clone(), CHARLIE, Side::North, 0);
+    assert_eq!(drop_stone_result, Err(GameError::NotPlayerTurn));
+
+    let drop_stone_result = Game::drop_stone(state, BOB, Side::North, 0);
+    assert!(drop_stone_result.is_ok());
+}
+
+#[test]
+fn last_move_changes_after_dropping_stone() {
+    let mut state =
+        Game::new_game(vec![BOB, ALICE], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.phase = GamePhase::Play;
+    assert_eq!(state.last_move, None);
+
+    for (player, side, position, landing) in [
+        (BOB, Side::West, 3, Coordinates::new(3, 5)),
+        (BOB, Side::East, 1, Coordinates::new(1, 0)),
+        (BOB, Side::North, 4, Coordinates::new(1, 4)),
+        (BOB, Side::South, 8, Coordinates::new(0, 8)),
+    ] {
+        let state = Game::drop_stone(state.clone(), player, side, position).unwrap();
+        assert_eq!(
+            state.last_move,
+            Some(LastMove::new(player, side, position, landing))
+        );
+    }
+}
+
+#[test]
+fn west_stone_stops_before_stones_like_before_blocks() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+
+    for cell in [Cell::Block, Cell::Stone(1)] {
+        let mut state = state.clone();
+        state.board.update_cell(Coordinates::new(3, 0), cell);
+        assert_eq!(
+            Game::drop_stone(state, ALICE, Side::West, 3),
+            Err(GameError::InvalidStonePosition)
+        );
+    }
+    for cell in [Cell::Block, Cell::Stone(1)] {
+        let mut state = state.clone();
+        state
+            .board
+            .update_cell(Coordinates::new(3, BOARD_WIDTH - 1), cell);
+        let state = Game::drop_stone(state, ALICE, Side::West, 3).unwrap();
+        assert_eq!(
+            state.last_move.unwrap().landing,
+            Coordinates::new(3, BOARD_WIDTH - 2)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn should_describe_last_move() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.board.update_cell(Coordinates::new(3, 3), Cell::Block);
+    state.phase = GamePhase::Play;
+    assert_eq!(state.describe_last_move(), None);
+
+    let state = Game::drop_stone(state, ALICE, Side::West, 3).unwrap();
+    assert_eq!(
+        state.describe_last_move(),
+        Some("11 dropped a stone from West at position 3, landing at (3,2)".to_string())
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn should_explain_finished_game() {
+    let mut state = Game::new_game(
+        vec![ALICE, BOB],
+        Some(INITIAL_SEED),
+        GENESIS_BLOCK,
+        Some(BoardConfig {
+            width: 4,
+            height: 4,
+            num_blocks: 0,
+        }),
+    )
+    .unwrap();
+    state.phase = GamePhase::Play;
+    for (row, col) in [(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (3, 0), (3, 1)] {
+        state
+            .board
+            .update_cell(Coordinates::new(row, col), Cell::Stone(0));
+    }
+    state
+        .board
+        .update_cell(Coordinates::new(3, 3), Cell::Stone(1));
+    assert!(state.explain().ends_with("moves: 0"));
+
+    let state = Game::drop_stone(state, ALICE, Side::North, 1).unwrap();
+    assert_eq!(
+        state.explain(),
+        [
+            "oo..",
+            "oo..",
+            "oo..",
+            "oo.x",
+            "phase: Play",
+            "winner: 11",
+            "squares: 11: 3, 22: 0",
+            "moves: 1",
+            "last move: 11 dropped a stone from North at position 1, landing at (0,1)",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn should_report_dropped_stone() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let o = Cell::Empty;
+    let a = Cell::Stone(state.player_index(&ALICE));
+    state.board = board_from_cells([
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, a, a, o, o, o, o, o, o, o],
+        [o, a, a, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, a, o, a],
+    ]);
+    state.phase = GamePhase::Play;
+
+    let (state, report) = Game::drop_stone_reported(state, ALICE, Side::North, 0).unwrap();
+    assert_eq!(
+        report,
+        MoveReport {
+            landing: Coordinates::new(9, 0),
+            next_player: BOB,
+            winner: None,
+            is_draw: false,
+        }
+    );
+
+    let (state, report) = Game::drop_stone_reported(state, BOB, Side::North, 3).unwrap();
+    assert_eq!(
+        report,
+        MoveReport {
+            landing: Coordinates::new(9, 3),
+            next_player: ALICE,
+            winner: None,
+            is_draw: false,
+        }
+    );
+
+    let (_, report) = Game::drop_stone_reported(state, ALICE, Side::North, 8).unwrap();
+    assert_eq!(
+        report,
+        MoveReport {
+            landing: Coordinates::new(9, 8),
+            next_player: BOB,
+            winner: None,
+            is_draw: false,
+        }
+    );
 }
 
 #[test]
-fn a_game_can_change_game_phase() {
-    let game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    assert_eq!(game_state.phase, GamePhase::Bomb);
-    let game_state = Game::change_game_phase(game_state, GamePhase::Play);
-    assert_eq!(game_state.phase, GamePhase::Play);
-    let game_state = Game::change_game_phase(game_state, GamePhase::Bomb);
-    assert_eq!(game_state.phase, GamePhase::Bomb);
-}
+fn should_report_winning_stone() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let o = Cell::Empty;
+    let a = Cell::Stone(state.player_index(&ALICE));
+    state.board = board_from_cells([
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, a, a, o, o, o, o, o, o, o],
+        [o, a, a, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, a],
+        [o, o, o, o, o, o, o, o, a, a],
+    ]);
+    state.phase = GamePhase::Play;
 
-#[test]
-fn a_player_cannot_drop_a_stone_in_bomb_phase() {
-    let state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    assert_eq!(state.phase, GamePhase::Bomb);
+    let (state, report) = Game::drop_stone_reported(state, ALICE, Side::North, 8).unwrap();
     assert_eq!(
-        Game::drop_stone(state, BOB, Side::North, 0),
-        Err(GameError::DroppedStoneOutsidePlayPhase)
+        report,
+        MoveReport {
+            landing: Coordinates::new(8, 8),
+            next_player: BOB,
+            winner: Some(ALICE),
+            is_draw: false,
+        }
     );
+    assert_eq!(state.winner, Some(ALICE));
 }
 
 #[test]
-fn a_player_cannot_drop_a_stone_out_of_turn() {
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+fn should_report_drawing_stone() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     state.phase = GamePhase::Play;
-    let drop_stone_result = Game::drop_stone(state, BOB, Side::North, 0);
-    assert_eq!(drop_stone_result, Err(GameError::NotPlayerTurn));
+    for row in 0..BOARD_HEIGHT {
+        for col in 0..BOARD_WIDTH {
+            state
+                .board
+                .update_cell(Coordinates::new(row, col), Cell::Stone((row + col) % 2));
+        }
+    }
+    state.board.update_cell(Coordinates::new(0, 5), Cell::Empty);
+
+    let (state, report) = Game::drop_stone_reported(state, ALICE, Side::North, 5).unwrap();
+    assert_eq!(
+        report,
+        MoveReport {
+            landing: Coordinates::new(0, 5),
+            next_player: BOB,
+            winner: None,
+            is_draw: true,
+        }
+    );
+    assert!(state.is_draw);
 }
 
 #[test]
-fn a_player_cannot_drop_stone_if_game_already_finished() {
-    let mut game_state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    game_state.phase = GamePhase::Play;
-    game_state.winner = Some(BOB);
-    assert_eq!(
-        Game::drop_stone(game_state, ALICE, Side::East, 1),
-        Err(GameError::GameAlreadyFinished),
-    )
+fn central_stones_should_score_higher_in_center_control() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let (alice_index, bob_index) = (state.player_index(&ALICE), state.player_index(&BOB));
+    state.board = Board::new();
+    assert_eq!(state.center_control(&ALICE), Ok(0));
+
+    state
+        .board
+        .update_cell(Coordinates::new(4, 5), Cell::Stone(alice_index));
+    state
+        .board
+        .update_cell(Coordinates::new(0, 9), Cell::Stone(bob_index));
+    assert_eq!(state.center_control(&ALICE), Ok(16));
+    assert_eq!(state.center_control(&BOB), Ok(-16));
+
+    state
+        .board
+        .update_cell(Coordinates::new(5, 0), Cell::Stone(bob_index));
+    assert_eq!(state.center_control(&ALICE), Ok(8));
+    assert_eq!(state.center_control(&BOB), Ok(-8));
+    let board = &state.board;
+    assert!(
+        Coordinates::new(4, 5).centrality(board) > Coordinates::new(5, 0).centrality(board),
+        "Central stones should weigh more than edge stones"
+    );
+    assert!(Coordinates::new(5, 0).centrality(board) > Coordinates::new(0, 9).centrality(board));
+    assert_eq!(state.center_control(&CHARLIE), Err(GameError::NotInGame));
 }
 
 #[test]
-fn player_turn_changes_after_dropping_stone() {
-    let mut state = Game::new_game(CHARLIE, BOB, Some(INITIAL_SEED));
-    for i in 0..BOARD_WIDTH {
-        state.board.update_cell(Coordinates::new(i, 0), Cell::Empty);
-    }
+fn should_archive_a_finished_game() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let o = Cell::Empty;
+    let a = Cell::Stone(state.player_index(&ALICE));
+    state.board = board_from_cells([
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, a, a, o, o, o, o, o, o, o],
+        [o, a, a, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, a, a, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, a],
+        [o, o, o, o, o, o, o, o, a, a],
+    ]);
     state.phase = GamePhase::Play;
-    let drop_stone_result = Game::drop_stone(state, CHARLIE, Side::North, 0);
-    assert!(drop_stone_result.is_ok());
-    let state = drop_stone_result.unwrap();
-
-    let drop_stone_result = Game::drop_stone(state, CHARLIE, Side::North, 0);
-    assert_eq!(drop_stone_result, Err(GameError::NotPlayerTurn));
+    assert_eq!(state.archive(), Err(GameError::GameNotFinished));
+
+    let state = Game::drop_stone(state, ALICE, Side::North, 8).unwrap();
+    let archive = state.archive().unwrap();
+    assert_eq!(archive.initial_seed, INITIAL_SEED);
+    assert_eq!(archive.genesis_block, GENESIS_BLOCK);
+    assert_eq!(archive.board, state.board);
+    assert_eq!(archive.players, vec![ALICE, BOB]);
+    assert_eq!(archive.outcome, GameOutcome::Win(ALICE));
+    assert_eq!(archive.move_index, state.move_index);
+    assert_eq!(archive.last_move, state.last_move);
+    assert!(archive.verify());
+
+    let encoded = archive.encode();
+    assert!(encoded.len() <= GameArchive::<u8>::max_encoded_len());
+    assert_eq!(
+        GameArchive::decode(&mut encoded.as_slice()),
+        Ok(archive.clone())
+    );
 
-    let drop_stone_result = Game::drop_stone(state, BOB, Side::North, 0);
-    assert!(drop_stone_result.is_ok());
+    let mut tampered = archive.clone();
+    tampered.outcome = GameOutcome::Win(BOB);
+    assert!(!tampered.verify());
+    let mut tampered = archive;
+    tampered.move_index += 1;
+    assert!(!tampered.verify());
 }
 
 #[test]
-fn last_move_changes_after_dropping_stone() {
-    let mut state = Game::new_game(BOB, ALICE, Some(INITIAL_SEED));
+fn should_archive_a_drawn_game() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     state.phase = GamePhase::Play;
-    assert_eq!(state.last_move, None);
+    state.move_index = 42;
+    state.is_draw = true;
 
-    for (player, side, position) in [
-        (BOB, Side::West, 2),
-        (BOB, Side::East, 1),
-        (BOB, Side::North, 6),
-        (BOB, Side::South, 8),
-    ] {
-        let state = Game::drop_stone(state, player, side, position).unwrap();
-        assert_eq!(state.last_move, Some(LastMove::new(player, side, position)));
-    }
+    let archive = state.archive().unwrap();
+    assert_eq!(archive.outcome, GameOutcome::Draw);
+    assert_eq!(archive.move_index, 42);
+    assert!(archive.verify());
 }
 
 #[test]
 fn a_stone_dropped_on_a_stone() {
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let (alice_index, bob_index) = (state.player_index(&ALICE), state.player_index(&BOB));
 
     let o = Cell::Empty;
@@ -426,7 +2661,7 @@ fn a_stone_dropped_on_a_stone() {
         [o, o, o, o, o, o, o, o, o, o],
     ];
 
-    state.board.cells = cells;
+    state.board = board_from_cells(cells);
     state.phase = GamePhase::Play;
 
     let state = Game::drop_stone(state, ALICE, Side::West, 0).unwrap();
@@ -442,13 +2677,14 @@ fn a_stone_dropped_on_a_stone() {
 
 #[test]
 fn a_stone_cannot_be_dropped_at_bounds() {
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     state.phase = GamePhase::Play;
 
-    let mut state_with_stones_at_bounds = state;
+    let mut state_with_stones_at_bounds = state.clone();
     let o = Cell::Empty;
     let x = Cell::Stone(state.player_index(&BOB));
-    state_with_stones_at_bounds.board.cells = [
+    state_with_stones_at_bounds.board = board_from_cells([
         [x, x, x, x, x, x, x, x, x, x],
         [x, o, o, o, o, o, o, o, o, x],
         [x, o, o, o, o, o, o, o, o, x],
@@ -459,11 +2695,11 @@ fn a_stone_cannot_be_dropped_at_bounds() {
         [x, o, o, o, o, o, o, o, o, x],
         [x, o, o, o, o, o, o, o, o, x],
         [x, x, x, x, x, x, x, x, x, x],
-    ];
+    ]);
 
     let mut state_with_blocks_at_bounds = state;
     let b = Cell::Block;
-    state_with_blocks_at_bounds.board.cells = [
+    state_with_blocks_at_bounds.board = board_from_cells([
         [b, b, b, b, b, b, b, b, b, b],
         [b, o, o, o, o, o, o, o, o, b],
         [b, o, o, o, o, o, o, o, o, b],
@@ -474,17 +2710,17 @@ fn a_stone_cannot_be_dropped_at_bounds() {
         [b, o, o, o, o, o, o, o, o, b],
         [b, o, o, o, o, o, o, o, o, b],
         [b, b, b, b, b, b, b, b, b, x],
-    ];
+    ]);
 
     for state in [state_with_stones_at_bounds, state_with_blocks_at_bounds] {
         // left -> right check, dropping stones from top and bottom
         for position in 0..BOARD_WIDTH {
             assert_eq!(
-                Game::drop_stone(state, ALICE, Side::North, position),
+                Game::drop_stone(state.clone(), ALICE, Side::North, position),
                 Err(GameError::InvalidStonePosition)
             );
             assert_eq!(
-                Game::drop_stone(state, ALICE, Side::South, position),
+                Game::drop_stone(state.clone(), ALICE, Side::South, position),
                 Err(GameError::InvalidStonePosition)
             );
         }
@@ -492,11 +2728,11 @@ fn a_stone_cannot_be_dropped_at_bounds() {
         // top -> bottom check, dropping stones from left and right
         for position in 0..BOARD_HEIGHT {
             assert_eq!(
-                Game::drop_stone(state, ALICE, Side::West, position),
+                Game::drop_stone(state.clone(), ALICE, Side::West, position),
                 Err(GameError::InvalidStonePosition)
             );
             assert_eq!(
-                Game::drop_stone(state, ALICE, Side::East, position),
+                Game::drop_stone(state.clone(), ALICE, Side::East, position),
                 Err(GameError::InvalidStonePosition)
             );
         }
@@ -520,8 +2756,9 @@ fn a_stone_dropped_from_north_side_should_move_until_it_reaches_an_obstacle() {
         [o, b, o, o, o, o, o, o, o, o],
     ];
 
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    state.board.cells = cells;
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = board_from_cells(cells);
     state.phase = GamePhase::Play;
 
     let state = Game::drop_stone(state, ALICE, Side::North, 0).unwrap();
@@ -564,9 +2801,10 @@ fn a_stone_dropped_from_south_side_should_move_until_it_reaches_an_obstacle() {
         [o, o, o, b, o, o, o, o, o, o],
     ];
 
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let (alice_index, bob_index) = (state.player_index(&ALICE), state.player_index(&BOB));
-    state.board.cells = cells;
+    state.board = board_from_cells(cells);
     state.phase = GamePhase::Play;
 
     let state = Game::drop_stone(state, ALICE, Side::South, 0).unwrap();
@@ -608,9 +2846,10 @@ fn a_stone_dropped_from_east_side_should_move_until_it_reaches_an_obstacle() {
         [o, o, o, o, o, o, o, o, o, o],
     ];
 
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let (alice_index, bob_index) = (state.player_index(&ALICE), state.player_index(&BOB));
-    state.board.cells = cells;
+    state.board = board_from_cells(cells);
     state.phase = GamePhase::Play;
 
     let state = Game::drop_stone(state, ALICE, Side::East, 0).unwrap();
@@ -652,8 +2891,9 @@ fn a_stone_dropped_from_west_side_should_move_until_it_reaches_an_obstacle() {
         [o, o, o, o, o, o, o, o, o, o],
     ];
 
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    state.board.cells = cells;
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = board_from_cells(cells);
     state.phase = GamePhase::Play;
 
     let state = Game::drop_stone(state, ALICE, Side::West, 0).unwrap();
@@ -680,11 +2920,12 @@ fn a_stone_dropped_from_west_side_should_move_until_it_reaches_an_obstacle() {
 
 #[test]
 fn a_player_wins_when_has_stones_in_three_squares() {
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let alice_index = state.player_index(&ALICE);
     let o = Cell::Empty;
     let s = Cell::Stone(alice_index);
-    state.board.cells = [
+    state.board = board_from_cells([
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, s, s, o, o, o, o, o, o],
         [o, o, s, s, o, o, o, o, o, o],
@@ -695,7 +2936,7 @@ fn a_player_wins_when_has_stones_in_three_squares() {
         [o, o, o, s, s, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
-    ];
+    ]);
 
     state = Game::check_winner_player(state);
     assert_eq!(state.winner, Some(ALICE));
@@ -703,11 +2944,12 @@ fn a_player_wins_when_has_stones_in_three_squares() {
 
 #[test]
 fn a_player_wins_when_has_stones_in_three_squares_with_overlap() {
-    let mut state = Game::new_game(CHARLIE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![CHARLIE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let winner_index = state.player_index(&BOB);
     let o = Cell::Empty;
     let w = Cell::Stone(winner_index);
-    state.board.cells = [
+    state.board = board_from_cells([
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
@@ -718,7 +2960,7 @@ fn a_player_wins_when_has_stones_in_three_squares_with_overlap() {
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
-    ];
+    ]);
 
     state = Game::check_winner_player(state);
     assert_eq!(state.winner, Some(BOB));
@@ -726,12 +2968,13 @@ fn a_player_wins_when_has_stones_in_three_squares_with_overlap() {
 
 #[test]
 fn no_player_wins_if_stones_are_not_in_four_squares() {
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
     let o = Cell::Empty;
     let b = Cell::Block;
     let r = Cell::Stone(state.player_index(&ALICE));
     let m = Cell::Stone(state.player_index(&BOB));
-    state.board.cells = [
+    state.board = board_from_cells([
         [o, r, o, o, o, o, o, o, m, o],
         [m, o, o, o, o, m, o, o, o, o],
         [m, o, r, r, m, m, m, o, o, o],
@@ -742,19 +2985,110 @@ fn no_player_wins_if_stones_are_not_in_four_squares() {
         [o, o, r, o, o, o, o, r, o, o],
         [r, r, r, o, o, o, o, o, o, o],
         [r, r, r, o, o, o, o, o, o, o],
-    ];
+    ]);
 
     state = Game::check_winner_player(state);
     assert!(state.winner.is_none(), "No player should have won");
 }
 
+#[test]
+fn compute_winner_should_find_players_with_enough_squares() {
+    let state = Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let o = Cell::Empty;
+    let b = Cell::Block;
+    let a = Cell::Stone(state.player_index(&ALICE));
+    let m = Cell::Stone(state.player_index(&BOB));
+
+    let three_squares = [
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, a, a, o, o, o, o, o, o],
+        [o, o, a, a, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, a, a, o, o, o],
+        [o, o, o, o, o, a, a, o, o, o],
+        [o, o, o, a, a, o, o, o, o, o],
+        [o, o, o, a, a, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+    ];
+    let three_squares_with_overlap = [
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, m, m, o, o, o, o],
+        [o, o, o, m, m, m, o, o, o, o],
+        [o, o, o, m, m, m, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+    ];
+    let two_squares_each = [
+        [a, a, o, o, o, o, o, o, m, m],
+        [a, a, o, o, o, o, o, o, m, m],
+        [o, o, o, o, b, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [m, m, o, o, o, o, o, o, a, a],
+        [m, m, o, o, o, o, o, o, a, a],
+    ];
+
+    for (cells, expected_squares, expected_winner) in [
+        (three_squares, vec![3, 0], Some(ALICE)),
+        (three_squares_with_overlap, vec![0, 3], Some(BOB)),
+        (two_squares_each, vec![2, 2], None),
+    ] {
+        let board = board_from_cells(cells);
+        assert_eq!(board.square_counts(state.players.len()), expected_squares);
+        assert_eq!(
+            board
+                .compute_winner(&state.players, NUM_OF_SQUARES_TO_WIN)
+                .map(|player_index| state.players[player_index as usize]),
+            expected_winner
+        );
+    }
+    assert_eq!(
+        board_from_cells(two_squares_each).compute_winner(&state.players, 2),
+        Some(state.player_index(&ALICE)),
+        "Ties go to the first player in turn order"
+    );
+}
+
+#[test]
+fn should_count_squares_per_player() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let o = Cell::Empty;
+    let a = Cell::Stone(state.player_index(&ALICE));
+    let m = Cell::Stone(state.player_index(&BOB));
+    assert_eq!(state.square_counts(), vec![(ALICE, 0), (BOB, 0)]);
+
+    state.board = board_from_cells([
+        [a, a, o, o, o, o, o, o, o, o],
+        [a, a, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, m, m, o, o, o, o],
+        [o, o, o, m, m, m, o, o, o, o],
+        [o, o, o, m, m, m, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, o, o],
+        [o, o, o, o, o, o, o, o, a, a],
+        [o, o, o, o, o, o, o, o, a, m],
+    ]);
+    assert_eq!(state.square_counts(), vec![(ALICE, 1), (BOB, 3)]);
+}
+
 #[test]
 fn should_play_a_game() {
     let o = Cell::Empty;
     let b = Cell::Block;
 
-    let mut state = Game::new_game(ALICE, BOB, Some(INITIAL_SEED));
-    state.board.cells = [
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = board_from_cells([
         [o, o, o, o, o, o, o, o, b, o],
         [b, o, o, o, o, o, o, o, o, o],
         [b, o, o, o, b, b, b, o, o, o],
@@ -765,7 +3099,7 @@ fn should_play_a_game() {
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
         [o, o, o, o, o, o, o, o, o, o],
-    ];
+    ]);
 
     // players1 drops bombs
     let player1_num_bombs = state.get_player_bombs(&ALICE).unwrap();
@@ -777,7 +3111,12 @@ fn should_play_a_game() {
         player1_num_bombs - 1
     );
 
-    let drop_bomb_result = Game::drop_bomb(state, Coordinates { row: 0, col: 0 }, ALICE, SECRET_1);
+    let drop_bomb_result = Game::drop_bomb(
+        state.clone(),
+        Coordinates { row: 0, col: 0 },
+        ALICE,
+        SECRET_1,
+    );
     assert!(
         drop_bomb_result.is_err(),
         "Player cannot drop two bombs in the same position"
@@ -803,7 +3142,12 @@ fn should_play_a_game() {
         player1_num_bombs - 3
     );
 
-    let drop_bomb_result = Game::drop_bomb(state, Coordinates { row: 6, col: 8 }, ALICE, SECRET_1);
+    let drop_bomb_result = Game::drop_bomb(
+        state.clone(),
+        Coordinates { row: 6, col: 8 },
+        ALICE,
+        SECRET_1,
+    );
     assert!(drop_bomb_result.is_err());
     assert_eq!(
         drop_bomb_result.unwrap_err(),
@@ -837,7 +3181,7 @@ fn should_play_a_game() {
         "The game should be in play phase after all bombs have been deployed"
     );
 
-    let drop_stone_result = Game::drop_stone(state, BOB, Side::North, 0);
+    let drop_stone_result = Game::drop_stone(state.clone(), BOB, Side::North, 0);
     assert!(drop_stone_result.is_err());
     assert_eq!(drop_stone_result.unwrap_err(), GameError::NotPlayerTurn);
 
@@ -895,8 +3239,8 @@ fn should_play_a_game() {
     let x = Cell::Stone(state.player_index(&ALICE));
     let y = Cell::Stone(state.player_index(&BOB));
     assert_eq!(
-        state.board.cells,
-        [
+        state.board,
+        board_from_cells([
             [x, o, o, x, o, x, o, o, b, o],
             [b, o, o, o, o, x, x, o, x, x],
             [b, o, o, o, b, b, b, o, x, x],
@@ -907,11 +3251,499 @@ fn should_play_a_game() {
             [o, o, y, o, o, o, o, o, o, o],
             [o, o, y, y, y, y, y, y, o, o],
             [o, o, y, o, o, o, o, o, o, o],
-        ]
+        ])
     );
 
     // trigger winning condition and check winner
+    assert_eq!(state.winning_move, None);
     state = Game::drop_stone(state, ALICE, Side::North, 6).unwrap();
     assert!(state.winner.is_some());
     assert_eq!(state.winner.unwrap(), ALICE);
+    assert_eq!(
+        state.winning_move,
+        Some(LastMove::new(ALICE, Side::North, 6, Coordinates::new(0, 6)))
+    );
+    assert_eq!(state.winning_move, state.last_move);
+}
+
+#[test]
+fn should_apply_many_actions() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+
+    let drop_bomb = |player, row, col, secret| GameAction::DropBomb {
+        player,
+        coords: Coordinates::new(row, col),
+        secret,
+    };
+    let actions = [
+        drop_bomb(ALICE, 0, 3, SECRET_1),
+        drop_bomb(ALICE, 5, 5, SECRET_1),
+        drop_bomb(ALICE, 6, 6, SECRET_1),
+        drop_bomb(BOB, 9, 0, SECRET_2),
+        drop_bomb(BOB, 9, 1, SECRET_2),
+        drop_bomb(BOB, 9, 2, SECRET_2),
+        GameAction::DropStone {
+            player: ALICE,
+            side: Side::North,
+            position: 5,
+        },
+        GameAction::DropStone {
+            player: BOB,
+            side: Side::South,
+            position: 4,
+        },
+        GameAction::Detonate {
+            player: ALICE,
+            coords: Coordinates::new(0, 3),
+            secret: SECRET_1,
+        },
+    ];
+
+    let (state, result) = Game::apply_many(state, &actions);
+    assert_eq!(result, Ok(()));
+    assert_eq!(state.phase, GamePhase::Play);
+    assert_eq!(
+        state.board.get_cell(&Coordinates::new(9, 5)),
+        Cell::Stone(state.player_index(&ALICE))
+    );
+    assert_eq!(state.board.get_cell(&Coordinates::new(0, 4)), Cell::Empty);
+    assert_eq!(state.get_player_bombs(&ALICE), Some(0));
+    assert_eq!(state.get_player_bombs(&BOB), Some(0));
+    assert_eq!(state.next_player, BOB);
+}
+
+#[test]
+fn apply_many_should_stop_at_the_first_failing_action() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+
+    let actions = [
+        GameAction::DropStone {
+            player: ALICE,
+            side: Side::North,
+            position: 5,
+        },
+        GameAction::DropStone {
+            player: ALICE,
+            side: Side::North,
+            position: 6,
+        },
+        GameAction::DropStone {
+            player: BOB,
+            side: Side::North,
+            position: 7,
+        },
+    ];
+
+    let (state, result) = Game::apply_many(state, &actions);
+    assert_eq!(result, Err((1, GameError::NotPlayerTurn)));
+    assert_eq!(
+        state.board.get_cell(&Coordinates::new(9, 5)),
+        Cell::Stone(state.player_index(&ALICE))
+    );
+    assert_eq!(state.board.get_cell(&Coordinates::new(9, 6)), Cell::Empty);
+    assert_eq!(state.board.get_cell(&Coordinates::new(9, 7)), Cell::Empty);
+    assert_eq!(state.next_player, BOB);
+    assert_eq!(
+        state.last_move,
+        Some(LastMove::new(ALICE, Side::North, 5, Coordinates::new(9, 5)))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn should_advance_a_four_player_bracket() {
+    let pairings = bracket::advance_round(&[
+        (ALICE, Some(BOB), Some(ALICE)),
+        (CHARLIE, Some(DAVE), Some(DAVE)),
+    ]);
+    assert_eq!(pairings, Ok(vec![(ALICE, Some(DAVE))]));
+
+    let pairings = bracket::advance_round(&[(ALICE, Some(DAVE), Some(DAVE))]);
+    assert_eq!(pairings, Ok(vec![]), "The tournament should be over");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn should_advance_a_five_player_bracket_with_byes() {
+    const EVE: u8 = 55;
+
+    let pairings = bracket::advance_round(&[
+        (ALICE, Some(BOB), Some(ALICE)),
+        (CHARLIE, Some(DAVE), Some(DAVE)),
+        (EVE, None, None),
+    ]);
+    assert_eq!(pairings, Ok(vec![(ALICE, Some(DAVE)), (EVE, None)]));
+
+    let pairings = bracket::advance_round(&[(ALICE, Some(DAVE), Some(DAVE)), (EVE, None, None)]);
+    assert_eq!(pairings, Ok(vec![(DAVE, Some(EVE))]));
+
+    let pairings = bracket::advance_round(&[(DAVE, Some(EVE), Some(EVE))]);
+    assert_eq!(pairings, Ok(vec![]), "The tournament should be over");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn should_not_advance_a_bracket_with_undecided_matches() {
+    assert_eq!(
+        bracket::advance_round(&[(ALICE, Some(BOB), Some(ALICE)), (CHARLIE, Some(DAVE), None)]),
+        Err(bracket::BracketError::UndecidedMatch(1))
+    );
+}
+
+#[test]
+fn should_verify_board_audit() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state = Game::drop_bomb(state, Coordinates::new(9, 1), ALICE, SECRET_1).unwrap();
+    state.phase = GamePhase::Play;
+
+    let mut audit = AuditLog::new(state.clone());
+    for action in [
+        GameAction::DropStone {
+            player: ALICE,
+            side: Side::North,
+            position: 0,
+        },
+        GameAction::DropStone {
+            player: BOB,
+            side: Side::North,
+            position: 1,
+        },
+        GameAction::Detonate {
+            player: ALICE,
+            coords: Coordinates::new(9, 1),
+            secret: SECRET_1,
+        },
+    ] {
+        state = audit.apply(state, action).unwrap();
+    }
+    assert_eq!(
+        audit.apply(
+            state.clone(),
+            GameAction::DropBomb {
+                player: ALICE,
+                coords: TEST_COORDINATES,
+                secret: SECRET_1,
+            }
+        ),
+        Err(GameError::DroppedBombOutsideBombPhase)
+    );
+    assert_eq!(audit.entries.len(), 3);
+    assert_eq!(audit.entries[2].move_index, 3);
+    assert_eq!(
+        audit.entries[0].changes,
+        vec![(Coordinates::new(9, 0), Cell::Empty, Cell::Stone(0))]
+    );
+    assert!(state.verify_audit(&audit));
+
+    let mut tampered = audit.clone();
+    tampered.entries[1].changes[0].2 = Cell::Stone(0);
+    assert!(!state.verify_audit(&tampered));
+
+    let mut tampered = audit.clone();
+    tampered.entries.pop();
+    assert!(!state.verify_audit(&tampered));
+
+    let mut tampered_state = state.clone();
+    tampered_state
+        .board
+        .update_cell(Coordinates::new(0, 0), Cell::Stone(1));
+    assert!(!tampered_state.verify_audit(&audit));
+}
+
+#[test]
+fn should_shuffle_turn_order_with_the_seed() {
+    let new_game = |seed| {
+        Game::new_game_with_shuffled_turn_order(
+            vec![ALICE, BOB, CHARLIE, DAVE],
+            Some(seed),
+            GENESIS_BLOCK,
+            None,
+        )
+        .unwrap()
+    };
+
+    let state = new_game(7);
+    assert_eq!(state.players, vec![DAVE, ALICE, BOB, CHARLIE]);
+    assert_eq!(state.next_player, DAVE);
+    assert_eq!(
+        state
+            .bombs
+            .iter()
+            .map(|(player, _)| *player)
+            .collect::<Vec<_>>(),
+        state.players.to_vec()
+    );
+    assert_eq!(state, new_game(7), "The shuffle should be reproducible");
+    assert_eq!(
+        new_game(INITIAL_SEED).players,
+        vec![ALICE, CHARLIE, DAVE, BOB]
+    );
+
+    let mut state = state;
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+    let state = Game::drop_stone(state, DAVE, Side::North, 0).unwrap();
+    assert_eq!(state.next_player, ALICE);
+}
+
+#[test]
+fn should_reject_stale_moves() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.phase = GamePhase::Play;
+    let drop_stone = |player, position| GameAction::DropStone {
+        player,
+        side: Side::North,
+        position,
+    };
+
+    assert_eq!(state.expected_next_move_index(), 0);
+    let state = Game::apply_expecting(state, drop_stone(ALICE, 0), 0).unwrap();
+    assert_eq!(state.expected_next_move_index(), 1);
+    assert_eq!(
+        Game::apply_expecting(state.clone(), drop_stone(ALICE, 0), 0),
+        Err(GameError::StaleMove),
+        "A resubmitted move should be rejected"
+    );
+    assert_eq!(
+        Game::apply_expecting(state.clone(), drop_stone(BOB, 1), 2),
+        Err(GameError::StaleMove)
+    );
+    let state = Game::apply_expecting(state, drop_stone(BOB, 1), 1).unwrap();
+    assert_eq!(state.expected_next_move_index(), 2);
+}
+
+#[test]
+fn should_rewind_to_any_past_move() {
+    let mut initial =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    initial.board = Board::new();
+
+    let history = [
+        GameAction::DropBomb {
+            player: ALICE,
+            coords: Coordinates::new(4, 4),
+            secret: SECRET_1,
+        },
+        GameAction::DropBomb {
+            player: BOB,
+            coords: Coordinates::new(5, 5),
+            secret: SECRET_2,
+        },
+        GameAction::DropBomb {
+            player: ALICE,
+            coords: Coordinates::new(4, 5),
+            secret: SECRET_1,
+        },
+        GameAction::DropBomb {
+            player: BOB,
+            coords: Coordinates::new(5, 4),
+            secret: SECRET_2,
+        },
+        GameAction::DropBomb {
+            player: ALICE,
+            coords: Coordinates::new(4, 6),
+            secret: SECRET_1,
+        },
+        GameAction::DropBomb {
+            player: BOB,
+            coords: Coordinates::new(5, 6),
+            secret: SECRET_2,
+        },
+        GameAction::DropStone {
+            player: ALICE,
+            side: Side::North,
+            position: 1,
+        },
+        GameAction::DropStone {
+            player: BOB,
+            side: Side::North,
+            position: 1,
+        },
+        GameAction::Detonate {
+            player: ALICE,
+            coords: Coordinates::new(4, 4),
+            secret: SECRET_1,
+        },
+        GameAction::Detonate {
+            player: BOB,
+            coords: Coordinates::new(5, 5),
+            secret: SECRET_2,
+        },
+    ];
+
+    let mut live_states = vec![initial.clone()];
+    for action in history {
+        let state = Game::apply(live_states.last().unwrap().clone(), action).unwrap();
+        live_states.push(state);
+    }
+
+    for to_move in [0, 3, 6, 8, 10] {
+        assert_eq!(
+            Game::rewind(initial.clone(), &history, to_move),
+            Ok(live_states[to_move as usize].clone())
+        );
+    }
+    assert_eq!(
+        Game::rewind(initial, &history, history.len() as u32 + 1),
+        Err(GameError::InvalidMoveIndex)
+    );
+}
+
+#[test]
+fn should_replay_a_move_log() {
+    let initial =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    let mut moves = Vec::new();
+    for (player, secret, row) in [(ALICE, SECRET_1, 4), (BOB, SECRET_2, 5)] {
+        for col in 0..3 {
+            moves.push(Move::DropBomb {
+                player,
+                coords: Coordinates::new(row, col),
+                secret,
+            });
+        }
+    }
+    moves.push(Move::DropStone {
+        player: ALICE,
+        side: Side::South,
+        position: 2,
+    });
+    moves.push(Move::Detonate {
+        player: BOB,
+        coords: Coordinates::new(5, 0),
+        secret: SECRET_2,
+    });
+
+    let mut expected = initial.clone();
+    for action in moves.iter() {
+        expected = Game::apply(expected, *action).unwrap();
+    }
+    assert_eq!(Game::replay(initial.clone(), &moves), Ok(expected));
+    assert_eq!(Game::replay(initial.clone(), &[]), Ok(initial.clone()));
+
+    moves.insert(
+        7,
+        Move::DropStone {
+            player: ALICE,
+            side: Side::South,
+            position: 3,
+        },
+    );
+    assert_eq!(
+        Game::replay(initial.clone(), &moves),
+        Err((7, GameError::NotPlayerTurn))
+    );
+
+    let moves = [
+        Move::DropBomb {
+            player: ALICE,
+            coords: Coordinates::new(4, 0),
+            secret: SECRET_1,
+        },
+        Move::DropBomb {
+            player: CHARLIE,
+            coords: Coordinates::new(4, 1),
+            secret: SECRET_1,
+        },
+    ];
+    assert_eq!(
+        Game::replay(initial, &moves),
+        Err((1, GameError::NotInGame))
+    );
+}
+
+#[test]
+fn should_classify_moves() {
+    let mut state =
+        Game::new_game(vec![ALICE, BOB], Some(INITIAL_SEED), GENESIS_BLOCK, None).unwrap();
+    state.board = Board::new();
+    state.board.update_cell(Coordinates::new(0, 5), Cell::Block);
+
+    let drop_bomb = |player, row, col| GameAction::DropBomb {
+        player,
+        coords: Coordinates::new(row, col),
+        secret: SECRET_1,
+    };
+    let drop_stone = |player, side, position| GameAction::DropStone {
+        player,
+        side,
+        position,
+    };
+    let detonate = |player, row, col| GameAction::Detonate {
+        player,
+        coords: Coordinates::new(row, col),
+        secret: SECRET_1,
+    };
+
+    // Bomb phase
+    assert_eq!(
+        state.classify_move(&drop_bomb(BOB, 1, 1)),
+        MoveLegality::Legal
+    );
+    assert_eq!(
+        state.classify_move(&drop_bomb(ALICE, 0, 5)),
+        MoveLegality::Illegal(GameError::InvalidBombPosition)
+    );
+    assert_eq!(
+        state.classify_move(&drop_bomb(CHARLIE, 1, 1)),
+        MoveLegality::Illegal(GameError::NotInGame)
+    );
+    assert_eq!(
+        state.classify_move(&drop_stone(ALICE, Side::North, 1)),
+        MoveLegality::Illegal(GameError::DroppedStoneOutsidePlayPhase)
+    );
+    assert_eq!(
+        state.classify_move(&detonate(ALICE, 1, 1)),
+        MoveLegality::Illegal(GameError::DetonatedBombOutsidePlayPhase)
+    );
+
+    for col in 0..NUM_OF_BOMBS_PER_PLAYER as u8 {
+        state = Game::drop_bomb(state, Coordinates::new(1, col), ALICE, SECRET_1).unwrap();
+    }
+    assert_eq!(
+        state.classify_move(&drop_bomb(ALICE, 2, 2)),
+        MoveLegality::Illegal(GameError::NoMoreBombsAvailable)
+    );
+
+    // Play phase
+    state.phase = GamePhase::Play;
+    assert_eq!(
+        state.classify_move(&drop_bomb(BOB, 2, 2)),
+        MoveLegality::Illegal(GameError::DroppedBombOutsideBombPhase)
+    );
+    assert_eq!(
+        state.classify_move(&drop_stone(ALICE, Side::North, 1)),
+        MoveLegality::Legal
+    );
+    assert_eq!(
+        state.classify_move(&drop_stone(BOB, Side::North, 1)),
+        MoveLegality::Illegal(GameError::NotPlayerTurn)
+    );
+    assert_eq!(
+        state.classify_move(&drop_stone(ALICE, Side::North, 5)),
+        MoveLegality::Illegal(GameError::InvalidStonePosition)
+    );
+    assert_eq!(
+        state.classify_move(&detonate(ALICE, 1, 1)),
+        MoveLegality::Legal
+    );
+    assert_eq!(
+        state.classify_move(&detonate(ALICE, 2, 2)),
+        MoveLegality::Illegal(GameError::InvalidBombPosition)
+    );
+
+    state.winner = Some(BOB);
+    assert_eq!(
+        state.classify_move(&drop_stone(ALICE, Side::North, 1)),
+        MoveLegality::Illegal(GameError::GameAlreadyFinished)
+    );
 }
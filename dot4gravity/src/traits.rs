@@ -14,22 +14,21 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Coordinates, Position, BOARD_HEIGHT, BOARD_WIDTH};
+use crate::{Board, Coordinates, Position};
 
 pub(crate) trait Bound {
     /// Tells if something is inside the board.
-    fn is_inside_board(&self) -> bool;
+    fn is_inside_board(&self, board: &Board) -> bool;
 }
 
 impl Bound for Coordinates {
-    fn is_inside_board(&self) -> bool {
-        self.row < BOARD_WIDTH && self.col < BOARD_HEIGHT
+    fn is_inside_board(&self, board: &Board) -> bool {
+        self.row < board.height() && self.col < board.width()
     }
 }
 
 impl Bound for Position {
-    #[allow(clippy::redundant_comparisons)]
-    fn is_inside_board(&self) -> bool {
-        self < &BOARD_WIDTH && self < &BOARD_HEIGHT
+    fn is_inside_board(&self, board: &Board) -> bool {
+        self < &board.width() && self < &board.height()
     }
 }